@@ -79,7 +79,7 @@ impl AppRunner {
         let _ = (event_loop_function)(
             event_loop,
             move |event: winit::event::Event<()>,
-                  target: &winit::event_loop::EventLoopWindowTarget<()>| {
+                  _target: &winit::event_loop::EventLoopWindowTarget<()>| {
                 match event {
                     ref e if SurfaceWrapper::start_condition(e) => {
                         for window in windows.values_mut() {
@@ -181,7 +181,7 @@ impl Window {
     }
 
     /// - **iOS:** Can only be called on the main thread. Returns the top left coordinates of the
-    /// window in the screen space coordinate system.
+    ///   window in the screen space coordinate system.
     /// - **Web:** Returns the top-left coordinates relative to the viewport.
     pub fn outer_position_pixels(&self) -> Result<(i32, i32), winit::error::NotSupportedError> {
         self.window.outer_position().map(Into::into)
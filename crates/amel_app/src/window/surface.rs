@@ -10,6 +10,12 @@ pub struct SurfaceWrapper {
     config: Option<wgpu::SurfaceConfiguration>,
 }
 
+impl Default for SurfaceWrapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SurfaceWrapper {
     pub fn new() -> Self {
         Self {
@@ -66,7 +72,7 @@ impl SurfaceWrapper {
 
         // Get the default configuration,
         let mut config = surface
-            .get_default_config(&adapter, width, height)
+            .get_default_config(adapter, width, height)
             .expect("Surface isn't supported by the adapter.");
         if srgb {
             // Not all platforms (WebGPU) support sRGB swapchains, so we need to use view formats
@@ -79,7 +85,7 @@ impl SurfaceWrapper {
             config.view_formats.push(format);
         };
 
-        surface.configure(&device, &config);
+        surface.configure(device, &config);
         self.config = Some(config);
     }
 
@@ -89,7 +95,7 @@ impl SurfaceWrapper {
         config.width = width.max(1);
         config.height = height.max(1);
         let surface = self.surface.as_ref().unwrap();
-        surface.configure(&device, config);
+        surface.configure(device, config);
     }
 
     pub fn acquire(&mut self, device: &wgpu::Device) -> wgpu::SurfaceTexture {
@@ -124,7 +130,7 @@ impl SurfaceWrapper {
         }
     }
 
-    pub fn get(&self) -> Option<&wgpu::Surface> {
+    pub fn get(&self) -> Option<&wgpu::Surface<'_>> {
         self.surface.as_ref()
     }
 
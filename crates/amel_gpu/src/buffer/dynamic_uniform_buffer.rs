@@ -20,7 +20,7 @@ impl DynamicUniformBuffer {
         item_count: usize,
     ) -> Self {
         let alignment = device.limits().min_uniform_buffer_offset_alignment as usize;
-        let aligned_item_size = ((item_size + alignment - 1) / alignment) * alignment;
+        let aligned_item_size = item_size.div_ceil(alignment) * alignment;
         let buffer = Buffer::new(device, usage, item_size, item_count * aligned_item_size);
 
         Self {
@@ -38,7 +38,7 @@ impl DynamicUniformBuffer {
         bytes: &[u8],
     ) -> Self {
         let alignment = device.limits().min_uniform_buffer_offset_alignment as usize;
-        let aligned_item_size = ((item_size + alignment - 1) / alignment) * alignment;
+        let aligned_item_size = item_size.div_ceil(alignment) * alignment;
         let buffer = Buffer::new_with_data(device, usage, item_size, bytes);
 
         Self {
@@ -59,7 +59,7 @@ impl DynamicUniformBuffer {
         queue.write_buffer(&self.buffer, offset, bytes);
     }
 
-    pub fn binding(&self, index: usize) -> wgpu::BufferBinding {
+    pub fn binding(&self, index: usize) -> wgpu::BufferBinding<'_> {
         assert!(
             index * self.aligned_item_size < self.buffer.size(),
             "Index out of bounds"
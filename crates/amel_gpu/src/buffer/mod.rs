@@ -89,11 +89,11 @@ impl Buffer {
         queue.write_buffer(&self.buffer, offset, bytes);
     }
 
-    pub fn slice(&self) -> wgpu::BufferSlice {
+    pub fn slice(&self) -> wgpu::BufferSlice<'_> {
         self.buffer.slice(..)
     }
 
-    pub fn binding(&self) -> wgpu::BufferBinding {
+    pub fn binding(&self) -> wgpu::BufferBinding<'_> {
         wgpu::BufferBinding {
             buffer: &self.buffer,
             offset: 0,
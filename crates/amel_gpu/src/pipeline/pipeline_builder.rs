@@ -93,6 +93,15 @@ impl<'a> RenderPipelineBuilder<'a> {
         self
     }
 
+    /// Set the index format strips are drawn with, which enables primitive restart.
+    ///
+    /// Indexed strip meshes can only be drawn with a pipeline using their index format.
+    #[inline]
+    pub fn strip_index_format(mut self, format: Option<wgpu::IndexFormat>) -> Self {
+        self.primitive.strip_index_format = format;
+        self
+    }
+
     #[inline]
     pub fn front_face(mut self, front_face: wgpu::FrontFace) -> Self {
         self.primitive.front_face = front_face;
@@ -118,8 +127,8 @@ impl<'a> RenderPipelineBuilder<'a> {
         self
     }
 
-    /// Format of the depth/stencil buffer. Must be one of the depth formats. Must match the format
-    /// of the depth/stencil attachment.
+    // /// Format of the depth/stencil buffer. Must be one of the depth formats. Must match the
+    // /// format of the depth/stencil attachment.
     // #[inline]
     // pub fn depth_format(mut self, format: wgpu::TextureFormat) -> Self {
     //     let state = self
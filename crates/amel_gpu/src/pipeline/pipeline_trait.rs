@@ -6,6 +6,7 @@ pub trait PipelineTrait<'a> {
         color_target_states: Vec<Option<wgpu::ColorTargetState>>,
         depth_stencil_state: Option<wgpu::DepthStencilState>,
         primitive_topology: wgpu::PrimitiveTopology,
+        strip_index_format: Option<wgpu::IndexFormat>,
         sample_count: u32,
     ) -> wgpu::RenderPipeline {
         let pipeline_layout = Self::pipeline_layout(device);
@@ -37,6 +38,7 @@ pub trait PipelineTrait<'a> {
             .fragment_state(fragment_state)
             .depth_stencil(depth_stencil_state)
             .primitive_topology(primitive_topology)
+            .strip_index_format(strip_index_format)
             .sample_count(sample_count)
             .build(device)
     }
@@ -19,7 +19,7 @@ impl VertexAttributes {
         self
     }
 
-    pub fn vertex_buffer_layout(&self) -> wgpu::VertexBufferLayout {
+    pub fn vertex_buffer_layout(&self) -> wgpu::VertexBufferLayout<'_> {
         wgpu::VertexBufferLayout {
             array_stride: self.array_stride,
             step_mode: wgpu::VertexStepMode::Vertex,
@@ -50,7 +50,7 @@ impl VertexBufferLayoutsBuilder {
         self
     }
 
-    pub fn build(&self) -> Vec<wgpu::VertexBufferLayout> {
+    pub fn build(&self) -> Vec<wgpu::VertexBufferLayout<'_>> {
         self.attributes
             .iter()
             .map(|attr| attr.vertex_buffer_layout())
@@ -0,0 +1,111 @@
+use super::BoundingCircle;
+use crate::prelude::*;
+
+/// A 2D axis-aligned bounding box
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb2d {
+    /// The minimum point of the box
+    pub min: Vec2,
+    /// The maximum point of the box
+    pub max: Vec2,
+}
+
+impl Aabb2d {
+    /// Create a new `Aabb2d` from its center and half size
+    #[inline(always)]
+    pub fn new(center: Vec2, half_size: Vec2) -> Self {
+        debug_assert!(half_size.cmpge(Vec2::ZERO).all());
+        Self {
+            min: center - half_size,
+            max: center + half_size,
+        }
+    }
+
+    /// Create a new `Aabb2d` from two corner points
+    #[inline(always)]
+    pub fn from_corners(point1: Vec2, point2: Vec2) -> Self {
+        Self {
+            min: point1.min(point2),
+            max: point1.max(point2),
+        }
+    }
+
+    /// Compute the smallest `Aabb2d` containing all of the given points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given set of points is empty.
+    pub fn from_point_cloud(points: impl IntoIterator<Item = Vec2>) -> Self {
+        let mut iter = points.into_iter();
+        let first = iter
+            .next()
+            .expect("point cloud must contain at least one point for Aabb2d construction");
+        let (min, max) = iter.fold((first, first), |(min, max), point| {
+            (min.min(point), max.max(point))
+        });
+        Self { min, max }
+    }
+
+    #[inline(always)]
+    pub fn center(&self) -> Vec2 {
+        (self.min + self.max) / 2.0
+    }
+
+    #[inline(always)]
+    pub fn half_size(&self) -> Vec2 {
+        (self.max - self.min) / 2.0
+    }
+
+    #[inline(always)]
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// Returns `true` if `other` lies entirely inside this box
+    #[inline(always)]
+    pub fn contains(&self, other: &Self) -> bool {
+        other.min.cmpge(self.min).all() && other.max.cmple(self.max).all()
+    }
+
+    #[inline(always)]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+
+    #[inline(always)]
+    pub fn intersects_circle(&self, circle: &BoundingCircle) -> bool {
+        self.closest_point(circle.center)
+            .distance_squared(circle.center)
+            <= circle.radius() * circle.radius()
+    }
+
+    /// Finds the point on the box that is closest to the given `point`
+    #[inline(always)]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        point.clamp(self.min, self.max)
+    }
+
+    /// Get the smallest box containing both `self` and `other`
+    #[inline(always)]
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Grow the box by `amount` on every side
+    #[inline(always)]
+    pub fn grow(&self, amount: Vec2) -> Self {
+        Self {
+            min: self.min - amount,
+            max: self.max + amount,
+        }
+    }
+}
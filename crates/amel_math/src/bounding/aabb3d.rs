@@ -0,0 +1,121 @@
+use super::BoundingSphere;
+use crate::prelude::*;
+
+/// A 3D axis-aligned bounding box
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb3d {
+    /// The minimum point of the box
+    pub min: Vec3,
+    /// The maximum point of the box
+    pub max: Vec3,
+}
+
+impl Aabb3d {
+    /// Create a new `Aabb3d` from its center and half size
+    #[inline(always)]
+    pub fn new(center: Vec3, half_size: Vec3) -> Self {
+        debug_assert!(half_size.cmpge(Vec3::ZERO).all());
+        Self {
+            min: center - half_size,
+            max: center + half_size,
+        }
+    }
+
+    /// Create a new `Aabb3d` from two corner points
+    #[inline(always)]
+    pub fn from_corners(point1: Vec3, point2: Vec3) -> Self {
+        Self {
+            min: point1.min(point2),
+            max: point1.max(point2),
+        }
+    }
+
+    /// Compute the smallest `Aabb3d` containing all of the given points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given set of points is empty.
+    pub fn from_point_cloud(points: impl IntoIterator<Item = Vec3>) -> Self {
+        let mut iter = points.into_iter();
+        let first = iter
+            .next()
+            .expect("point cloud must contain at least one point for Aabb3d construction");
+        let (min, max) = iter.fold((first, first), |(min, max), point| {
+            (min.min(point), max.max(point))
+        });
+        Self { min, max }
+    }
+
+    #[inline(always)]
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) / 2.0
+    }
+
+    #[inline(always)]
+    pub fn half_size(&self) -> Vec3 {
+        (self.max - self.min) / 2.0
+    }
+
+    #[inline(always)]
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// Returns `true` if `other` lies entirely inside this box
+    #[inline(always)]
+    pub fn contains(&self, other: &Self) -> bool {
+        other.min.cmpge(self.min).all() && other.max.cmple(self.max).all()
+    }
+
+    #[inline(always)]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+
+    #[inline(always)]
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        self.closest_point(sphere.center)
+            .distance_squared(sphere.center)
+            <= sphere.radius() * sphere.radius()
+    }
+
+    /// Finds the point on the box that is closest to the given `point`
+    #[inline(always)]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point.clamp(self.min, self.max)
+    }
+
+    /// Get the smallest box containing both `self` and `other`
+    #[inline(always)]
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Grow the box by `amount` on every side
+    #[inline(always)]
+    pub fn grow(&self, amount: Vec3) -> Self {
+        Self {
+            min: self.min - amount,
+            max: self.max + amount,
+        }
+    }
+
+    /// Get the box containing this one after it is transformed by `transform`
+    pub fn transformed_by(&self, transform: &Mat4) -> Self {
+        let center = transform.transform_point3(self.center());
+        let half_size = self.half_size();
+        let half_size = transform.x_axis.truncate().abs() * half_size.x
+            + transform.y_axis.truncate().abs() * half_size.y
+            + transform.z_axis.truncate().abs() * half_size.z;
+        Self::new(center, half_size)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::prelude::*;
+
+/// A trait for 2D primitives that can compute their bounding volumes
+pub trait Bounded2d {
+    /// Get an axis-aligned bounding box for the shape placed by `isometry`
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d;
+
+    /// Get a bounding circle for the shape placed by `isometry`
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle;
+}
+
+/// Bound a set of local vertices placed by `isometry`.
+///
+/// Shapes without vertices get an empty box at their local origin.
+fn point_cloud_aabb_2d(vertices: &[Vec2], isometry: Isometry2d) -> Aabb2d {
+    if vertices.is_empty() {
+        return Aabb2d::new(isometry.translation, Vec2::ZERO);
+    }
+    Aabb2d::from_point_cloud(vertices.iter().map(|&v| isometry.transform_point(v)))
+}
+
+/// Bound a set of local vertices placed by `isometry`.
+///
+/// The circle is computed in local space so that it does not change with the rotation.
+/// Shapes without vertices get an empty circle at their local origin.
+fn point_cloud_bounding_circle(vertices: &[Vec2], isometry: Isometry2d) -> BoundingCircle {
+    if vertices.is_empty() {
+        return BoundingCircle::new(isometry.translation, 0.0);
+    }
+    let local = BoundingCircle::from_point_cloud(vertices);
+    BoundingCircle::new(isometry.transform_point(local.center), local.radius())
+}
+
+impl Bounded2d for Circle {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        Aabb2d::new(isometry.into().translation, Vec2::splat(self.radius))
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        BoundingCircle::new(isometry.into().translation, self.radius)
+    }
+}
+
+impl Bounded2d for Ellipse {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        // The extent along each world axis of an ellipse rotated by theta
+        let isometry = isometry.into();
+        let (sin, cos) = isometry.rotation.sin_cos();
+        let (a, b) = (self.half_size.x, self.half_size.y);
+        let half_size = Vec2::new(
+            ((a * cos).powi(2) + (b * sin).powi(2)).sqrt(),
+            ((a * sin).powi(2) + (b * cos).powi(2)).sqrt(),
+        );
+        Aabb2d::new(isometry.translation, half_size)
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        BoundingCircle::new(isometry.into().translation, self.semi_major())
+    }
+}
+
+impl Bounded2d for Rectangle {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        // Project the rotated half extents onto the world axes
+        let isometry = isometry.into();
+        let rotation = Mat2::from_angle(isometry.rotation);
+        let half_size = self.size / 2.0;
+        let half_size = rotation.x_axis.abs() * half_size.x + rotation.y_axis.abs() * half_size.y;
+        Aabb2d::new(isometry.translation, half_size)
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        BoundingCircle::new(isometry.into().translation, self.size.length() / 2.0)
+    }
+}
+
+impl Bounded2d for RegularPolygon {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        let isometry = isometry.into();
+        Aabb2d::from_point_cloud(
+            self.vertices(isometry.rotation)
+                .into_iter()
+                .map(|v| v + isometry.translation),
+        )
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        BoundingCircle::new(isometry.into().translation, self.circumradius())
+    }
+}
+
+impl Bounded2d for Segment2d {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        point_cloud_aabb_2d(&[self.a(), self.b()], isometry.into())
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        let isometry = isometry.into();
+        let center = isometry.transform_point((self.a() + self.b()) / 2.0);
+        BoundingCircle::new(center, self.a().distance(self.b()) / 2.0)
+    }
+}
+
+impl Bounded2d for Triangle2d {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        point_cloud_aabb_2d(&self.vertices, isometry.into())
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        point_cloud_bounding_circle(&self.vertices, isometry.into())
+    }
+}
+
+impl Bounded2d for BoxedPolygon {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        point_cloud_aabb_2d(&self.vertices, isometry.into())
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        point_cloud_bounding_circle(&self.vertices, isometry.into())
+    }
+}
+
+impl<const N: usize> Bounded2d for Polyline2d<N> {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        point_cloud_aabb_2d(&self.vertices, isometry.into())
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        point_cloud_bounding_circle(&self.vertices, isometry.into())
+    }
+}
+
+impl Bounded2d for BoxedPolyline2d {
+    fn aabb_2d(&self, isometry: impl Into<Isometry2d>) -> Aabb2d {
+        point_cloud_aabb_2d(&self.vertices, isometry.into())
+    }
+
+    fn bounding_circle(&self, isometry: impl Into<Isometry2d>) -> BoundingCircle {
+        point_cloud_bounding_circle(&self.vertices, isometry.into())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::prelude::*;
+
+/// A trait for 3D primitives that can compute their bounding volumes
+pub trait Bounded3d {
+    /// Get an axis-aligned bounding box for the shape placed by `isometry`
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d;
+
+    /// Get a bounding sphere for the shape placed by `isometry`
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere;
+}
+
+/// Bound a set of local vertices placed by `isometry`.
+///
+/// Shapes without vertices get an empty box at their local origin.
+fn point_cloud_aabb_3d(vertices: &[Vec3], isometry: Isometry3d) -> Aabb3d {
+    if vertices.is_empty() {
+        return Aabb3d::new(isometry.translation, Vec3::ZERO);
+    }
+    Aabb3d::from_point_cloud(vertices.iter().map(|&v| isometry.transform_point(v)))
+}
+
+/// Bound a set of local vertices placed by `isometry`.
+///
+/// The sphere is computed in local space so that it does not change with the rotation.
+/// Shapes without vertices get an empty sphere at their local origin.
+fn point_cloud_bounding_sphere(vertices: &[Vec3], isometry: Isometry3d) -> BoundingSphere {
+    if vertices.is_empty() {
+        return BoundingSphere::new(isometry.translation, 0.0);
+    }
+    let local = BoundingSphere::from_point_cloud(vertices);
+    BoundingSphere::new(isometry.transform_point(local.center), local.radius())
+}
+
+impl Bounded3d for Sphere {
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d {
+        Aabb3d::new(isometry.into().translation, Vec3::splat(self.radius))
+    }
+
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere {
+        BoundingSphere::new(isometry.into().translation, self.radius)
+    }
+}
+
+impl Bounded3d for Cuboid {
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d {
+        // Project the rotated half extents onto the world axes
+        let isometry = isometry.into();
+        let rotation = Mat3::from_quat(isometry.rotation);
+        let half_size = rotation.x_axis.abs() * self.half_size.x
+            + rotation.y_axis.abs() * self.half_size.y
+            + rotation.z_axis.abs() * self.half_size.z;
+        Aabb3d::new(isometry.translation, half_size)
+    }
+
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere {
+        BoundingSphere::new(isometry.into().translation, self.half_size.length())
+    }
+}
+
+impl Bounded3d for Cylinder {
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d {
+        // The cylinder's axis is the local Y axis; each cap disc extends by the radius
+        // scaled by how far the world axis is from the cylinder's axis
+        let isometry = isometry.into();
+        let axis = isometry.rotation * Vec3::Y;
+        let caps = (axis * self.half_height).abs();
+        let discs = (Vec3::ONE - axis * axis).max(Vec3::ZERO).powf(0.5) * self.radius;
+        Aabb3d::new(isometry.translation, caps + discs)
+    }
+
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere {
+        BoundingSphere::new(
+            isometry.into().translation,
+            Vec2::new(self.radius, self.half_height).length(),
+        )
+    }
+}
+
+impl<const N: usize> Bounded3d for Polyline3d<N> {
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d {
+        point_cloud_aabb_3d(&self.vertices, isometry.into())
+    }
+
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere {
+        point_cloud_bounding_sphere(&self.vertices, isometry.into())
+    }
+}
+
+impl Bounded3d for BoxedPolyline3d {
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d {
+        point_cloud_aabb_3d(&self.vertices, isometry.into())
+    }
+
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere {
+        point_cloud_bounding_sphere(&self.vertices, isometry.into())
+    }
+}
+
+impl Bounded3d for Triangle3d {
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d {
+        point_cloud_aabb_3d(&self.vertices, isometry.into())
+    }
+
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere {
+        point_cloud_bounding_sphere(&self.vertices, isometry.into())
+    }
+}
+
+impl Bounded3d for ConvexHull3d {
+    fn aabb_3d(&self, isometry: impl Into<Isometry3d>) -> Aabb3d {
+        point_cloud_aabb_3d(&self.points, isometry.into())
+    }
+
+    fn bounding_sphere(&self, isometry: impl Into<Isometry3d>) -> BoundingSphere {
+        point_cloud_bounding_sphere(&self.points, isometry.into())
+    }
+}
@@ -0,0 +1,73 @@
+use super::Aabb2d;
+use crate::prelude::*;
+
+/// A bounding circle
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingCircle {
+    /// The center of the bounding circle
+    pub center: Vec2,
+    /// The circle
+    pub circle: Circle,
+}
+
+impl BoundingCircle {
+    /// Create a new `BoundingCircle` from its center and radius
+    #[inline(always)]
+    pub fn new(center: Vec2, radius: f32) -> Self {
+        debug_assert!(radius >= 0.0);
+        Self {
+            center,
+            circle: Circle { radius },
+        }
+    }
+
+    /// Compute a `BoundingCircle` containing all of the given points.
+    ///
+    /// The circle is centered on the points' bounding box, so it is not necessarily the smallest one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given set of points is empty.
+    pub fn from_point_cloud(points: &[Vec2]) -> Self {
+        let center = Aabb2d::from_point_cloud(points.iter().copied()).center();
+        let radius_squared = points
+            .iter()
+            .map(|point| point.distance_squared(center))
+            .fold(0.0, f32::max);
+        Self::new(center, radius_squared.sqrt())
+    }
+
+    #[inline(always)]
+    pub fn radius(&self) -> f32 {
+        self.circle.radius
+    }
+
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.distance_squared(self.center) <= self.radius() * self.radius()
+    }
+
+    /// Returns `true` if `other` lies entirely inside this circle
+    #[inline(always)]
+    pub fn contains(&self, other: &Self) -> bool {
+        self.center.distance(other.center) + other.radius() <= self.radius()
+    }
+
+    #[inline(always)]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let radius = self.radius() + other.radius();
+        self.center.distance_squared(other.center) <= radius * radius
+    }
+
+    #[inline(always)]
+    pub fn intersects_aabb(&self, aabb: &Aabb2d) -> bool {
+        aabb.intersects_circle(self)
+    }
+
+    /// Get an axis-aligned bounding box containing the circle
+    #[inline(always)]
+    pub fn aabb_2d(&self) -> Aabb2d {
+        Aabb2d::new(self.center, Vec2::splat(self.radius()))
+    }
+}
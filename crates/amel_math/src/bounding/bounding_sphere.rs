@@ -0,0 +1,94 @@
+use super::Aabb3d;
+use crate::prelude::*;
+
+/// A bounding sphere
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingSphere {
+    /// The center of the bounding sphere
+    pub center: Vec3,
+    /// The sphere
+    pub sphere: Sphere,
+}
+
+impl BoundingSphere {
+    /// Create a new `BoundingSphere` from its center and radius
+    #[inline(always)]
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        debug_assert!(radius >= 0.0);
+        Self {
+            center,
+            sphere: Sphere { radius },
+        }
+    }
+
+    /// Compute a `BoundingSphere` containing all of the given points.
+    ///
+    /// The sphere is centered on the points' bounding box, so it is not necessarily the smallest one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given set of points is empty.
+    pub fn from_point_cloud(points: &[Vec3]) -> Self {
+        let center = Aabb3d::from_point_cloud(points.iter().copied()).center();
+        let radius_squared = points
+            .iter()
+            .map(|point| point.distance_squared(center))
+            .fold(0.0, f32::max);
+        Self::new(center, radius_squared.sqrt())
+    }
+
+    #[inline(always)]
+    pub fn radius(&self) -> f32 {
+        self.sphere.radius
+    }
+
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.distance_squared(self.center) <= self.radius() * self.radius()
+    }
+
+    /// Returns `true` if `other` lies entirely inside this sphere
+    #[inline(always)]
+    pub fn contains(&self, other: &Self) -> bool {
+        self.center.distance(other.center) + other.radius() <= self.radius()
+    }
+
+    #[inline(always)]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let radius = self.radius() + other.radius();
+        self.center.distance_squared(other.center) <= radius * radius
+    }
+
+    #[inline(always)]
+    pub fn intersects_aabb(&self, aabb: &Aabb3d) -> bool {
+        aabb.intersects_sphere(self)
+    }
+
+    /// Finds the point on the sphere that is closest to the given `point`
+    #[inline(always)]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        PointQuery3d::closest_point(&self.sphere, self.center, point)
+    }
+
+    /// Get the smallest sphere containing both `self` and `other`
+    pub fn merge(&self, other: &Self) -> Self {
+        let offset = other.center - self.center;
+        let distance = offset.length();
+        if distance + other.radius() <= self.radius() {
+            return *self;
+        }
+        if distance + self.radius() <= other.radius() {
+            return *other;
+        }
+        let radius = (distance + self.radius() + other.radius()) / 2.0;
+        let center = self.center + offset * ((radius - self.radius()) / distance);
+        Self::new(center, radius)
+    }
+
+    /// Get an axis-aligned bounding box containing the sphere
+    #[inline(always)]
+    pub fn aabb_3d(&self) -> Aabb3d {
+        Aabb3d::new(self.center, Vec3::splat(self.radius()))
+    }
+}
@@ -0,0 +1,151 @@
+use super::{Aabb3d, BoundingSphere, Intersection};
+use crate::prelude::*;
+
+/// A view frustum, stored as six planes whose normals point into the frustum.
+///
+/// Each plane is stored as `(normal, distance)` such that points `p` inside the frustum
+/// satisfy `normal.dot(p) + distance >= 0.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum {
+    /// The left, right, bottom, top, near and far planes
+    pub planes: [Vec4; 6],
+}
+
+impl Frustum {
+    pub const LEFT: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const TOP: usize = 3;
+    pub const NEAR: usize = 4;
+    pub const FAR: usize = 5;
+
+    /// Extract the frustum from a view-projection matrix.
+    ///
+    /// The matrix is expected to map depth to `0.0..=1.0`, as the projections in glam and wgpu do.
+    /// The plane at infinity of an infinite projection keeps every point inside.
+    pub fn from_view_projection(view_projection: &Mat4) -> Self {
+        let row0 = view_projection.row(0);
+        let row1 = view_projection.row(1);
+        let row2 = view_projection.row(2);
+        let row3 = view_projection.row(3);
+
+        let planes = [
+            row3 + row0,
+            row3 - row0,
+            row3 + row1,
+            row3 - row1,
+            row2,
+            row3 - row2,
+        ]
+        .map(|plane| {
+            // The plane at infinity of an infinite projection has no normal, nothing lies beyond it
+            let length = plane.truncate().length();
+            if length > f32::EPSILON {
+                plane / length
+            } else {
+                Vec4::W
+            }
+        });
+
+        Self { planes }
+    }
+
+    /// Get the signed distance from the plane at `index` to `point`, positive on the inside
+    #[inline(always)]
+    pub fn signed_distance(&self, index: usize, point: Vec3) -> f32 {
+        let plane = self.planes[index];
+        plane.truncate().dot(point) + plane.w
+    }
+
+    #[inline(always)]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        (0..6).all(|i| self.signed_distance(i, point) >= 0.0)
+    }
+
+    /// Test a box oriented by `axes`, where each axis is scaled by the box's half size
+    fn intersect_box(&self, center: Vec3, axes: [Vec3; 3]) -> Intersection {
+        let mut result = Intersection::Inside;
+        for (i, plane) in self.planes.iter().enumerate() {
+            let normal = plane.truncate();
+            let radius = axes.iter().map(|axis| normal.dot(*axis).abs()).sum::<f32>();
+            let distance = self.signed_distance(i, center);
+            if distance < -radius {
+                return Intersection::Outside;
+            }
+            if distance < radius {
+                result = Intersection::Intersecting;
+            }
+        }
+        result
+    }
+
+    fn intersect_center_radius(&self, center: Vec3, radius: f32) -> Intersection {
+        let mut result = Intersection::Inside;
+        for i in 0..6 {
+            let distance = self.signed_distance(i, center);
+            if distance < -radius {
+                return Intersection::Outside;
+            }
+            if distance < radius {
+                result = Intersection::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Test whether an axis-aligned bounding box lies inside, outside or across the frustum
+    pub fn intersect_aabb(&self, aabb: &Aabb3d) -> Intersection {
+        let half_size = aabb.half_size();
+        self.intersect_box(
+            aabb.center(),
+            [
+                Vec3::X * half_size.x,
+                Vec3::Y * half_size.y,
+                Vec3::Z * half_size.z,
+            ],
+        )
+    }
+
+    /// Test whether a bounding sphere lies inside, outside or across the frustum
+    pub fn intersect_bounding_sphere(&self, sphere: &BoundingSphere) -> Intersection {
+        self.intersect_center_radius(sphere.center, sphere.radius())
+    }
+
+    /// Test whether a [`Sphere`] placed by `isometry` lies inside, outside or across the frustum
+    pub fn intersect_sphere(
+        &self,
+        sphere: &Sphere,
+        isometry: impl Into<Isometry3d>,
+    ) -> Intersection {
+        self.intersect_center_radius(isometry.into().translation, sphere.radius)
+    }
+
+    /// Test whether a [`Cuboid`] placed by `isometry` lies inside, outside or across the frustum
+    pub fn intersect_cuboid(
+        &self,
+        cuboid: &Cuboid,
+        isometry: impl Into<Isometry3d>,
+    ) -> Intersection {
+        let isometry = isometry.into();
+        self.intersect_box(
+            isometry.translation,
+            [
+                isometry.rotation * Vec3::X * cuboid.half_size.x,
+                isometry.rotation * Vec3::Y * cuboid.half_size.y,
+                isometry.rotation * Vec3::Z * cuboid.half_size.z,
+            ],
+        )
+    }
+
+    /// Returns `false` only if the box is certainly outside the frustum
+    #[inline(always)]
+    pub fn intersects_aabb(&self, aabb: &Aabb3d) -> bool {
+        self.intersect_aabb(aabb).is_visible()
+    }
+
+    /// Returns `false` only if the sphere is certainly outside the frustum
+    #[inline(always)]
+    pub fn intersects_bounding_sphere(&self, sphere: &BoundingSphere) -> bool {
+        self.intersect_bounding_sphere(sphere).is_visible()
+    }
+}
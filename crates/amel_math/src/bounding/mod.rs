@@ -0,0 +1,34 @@
+pub mod aabb2d;
+pub mod aabb3d;
+pub mod bounded2d;
+pub mod bounded3d;
+pub mod bounding_circle;
+pub mod bounding_sphere;
+pub mod frustum;
+
+pub use aabb2d::*;
+pub use aabb3d::*;
+pub use bounded2d::*;
+pub use bounded3d::*;
+pub use bounding_circle::*;
+pub use bounding_sphere::*;
+pub use frustum::*;
+
+/// The result of testing whether a volume lies within another one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Intersection {
+    /// The volume lies entirely inside
+    Inside,
+    /// The volume lies entirely outside
+    Outside,
+    /// The volume is partially inside
+    Intersecting,
+}
+
+impl Intersection {
+    /// Returns `true` unless the volume lies entirely outside
+    #[inline(always)]
+    pub fn is_visible(self) -> bool {
+        self != Intersection::Outside
+    }
+}
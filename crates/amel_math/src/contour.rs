@@ -0,0 +1,238 @@
+use crate::prelude::*;
+
+/// A regular 2D grid of scalar samples.
+///
+/// Sample `(x, y)` is located at `origin + Vec2::new(x, y) * cell_size`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScalarGrid {
+    pub width: usize,
+    pub height: usize,
+    pub origin: Vec2,
+    pub cell_size: Vec2,
+    /// The samples in row-major order, `width * height` long
+    pub values: Vec<f32>,
+}
+
+/// An isoline extracted from a [`ScalarGrid`].
+///
+/// Values above the level lie to the left of the polyline, so closed contours around
+/// maxima are counterclockwise and those around minima are clockwise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contour {
+    /// The iso-level this contour was extracted at
+    pub level: f32,
+    pub polyline: BoxedPolyline2d,
+    /// Whether the last vertex connects back to the first one
+    pub closed: bool,
+}
+
+impl Contour {
+    /// Get the contour as a polygon if it is closed
+    pub fn to_polygon(&self) -> Option<BoxedPolygon> {
+        self.closed
+            .then(|| BoxedPolygon::new(self.polyline.vertices.iter().copied()))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Edge {
+    Bottom,
+    Right,
+    Top,
+    Left,
+}
+
+impl ScalarGrid {
+    /// Create a new `ScalarGrid` from row-major `values`
+    pub fn new(
+        width: usize,
+        height: usize,
+        origin: Vec2,
+        cell_size: Vec2,
+        values: Vec<f32>,
+    ) -> Self {
+        assert_eq!(
+            values.len(),
+            width * height,
+            "grid has the wrong number of values"
+        );
+        Self {
+            width,
+            height,
+            origin,
+            cell_size,
+            values,
+        }
+    }
+
+    /// Sample `f` on a grid of `resolution` points spanning `min` to `max`
+    pub fn from_fn(min: Vec2, max: Vec2, resolution: UVec2, f: impl Fn(Vec2) -> f32) -> Self {
+        assert!(
+            resolution.x > 1 && resolution.y > 1,
+            "grid needs at least 2 samples on each axis"
+        );
+        let (width, height) = (resolution.x as usize, resolution.y as usize);
+        let cell_size = (max - min) / (resolution - UVec2::ONE).as_vec2();
+
+        let mut values = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                values.push(f(min + Vec2::new(x as f32, y as f32) * cell_size));
+            }
+        }
+        Self::new(width, height, min, cell_size, values)
+    }
+
+    #[inline(always)]
+    pub fn value(&self, x: usize, y: usize) -> f32 {
+        self.values[y * self.width + x]
+    }
+
+    #[inline(always)]
+    pub fn position(&self, x: usize, y: usize) -> Vec2 {
+        self.origin + Vec2::new(x as f32, y as f32) * self.cell_size
+    }
+
+    /// Extract the isolines at every one of `levels`
+    pub fn contours(&self, levels: &[f32]) -> Vec<Contour> {
+        levels
+            .iter()
+            .flat_map(|&level| self.contour(level))
+            .collect()
+    }
+
+    /// Extract the isolines at `level` with marching squares.
+    ///
+    /// Crossings are placed by linear interpolation along the cell edges, and ambiguous
+    /// saddle cells are resolved with the average of their corners.
+    pub fn contour(&self, level: f32) -> Vec<Contour> {
+        if self.width < 2 || self.height < 2 {
+            return Vec::new();
+        }
+
+        let horizontal_edges = (self.width - 1) * self.height;
+        let edge_count = horizontal_edges + self.width * (self.height - 1);
+
+        // Directed segments between edge crossings, keyed by the edge they start on
+        let mut segment_end = vec![usize::MAX; edge_count];
+        let mut has_start = vec![false; edge_count];
+        let mut is_end = vec![false; edge_count];
+        let mut points = vec![Vec2::ZERO; edge_count];
+
+        for y in 0..self.height - 1 {
+            for x in 0..self.width - 1 {
+                let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+                let values = corners.map(|(cx, cy)| self.value(cx, cy));
+                let above = values.map(|value| value > level);
+                let case = above
+                    .iter()
+                    .enumerate()
+                    .fold(0, |case, (i, &above)| case | ((above as usize) << i));
+
+                let center_above = values.iter().sum::<f32>() / 4.0 > level;
+                use Edge::*;
+                let segments: &[(Edge, Edge, usize)] = match case {
+                    1 => &[(Left, Bottom, 0)],
+                    2 => &[(Bottom, Right, 1)],
+                    3 => &[(Left, Right, 0)],
+                    4 => &[(Right, Top, 2)],
+                    5 if center_above => &[(Bottom, Right, 0), (Top, Left, 0)],
+                    5 => &[(Left, Bottom, 0), (Right, Top, 2)],
+                    6 => &[(Bottom, Top, 1)],
+                    7 => &[(Top, Left, 0)],
+                    8 => &[(Top, Left, 3)],
+                    9 => &[(Bottom, Top, 0)],
+                    10 if center_above => &[(Left, Bottom, 1), (Right, Top, 1)],
+                    10 => &[(Bottom, Right, 1), (Top, Left, 3)],
+                    11 => &[(Right, Top, 0)],
+                    12 => &[(Left, Right, 2)],
+                    13 => &[(Bottom, Right, 0)],
+                    14 => &[(Left, Bottom, 1)],
+                    _ => &[],
+                };
+
+                for &(a, b, reference) in segments {
+                    let (mut a, mut b) = (
+                        self.crossing(x, y, a, level, horizontal_edges),
+                        self.crossing(x, y, b, level, horizontal_edges),
+                    );
+                    // Keep the values above the level on the left
+                    let corner = self.position(corners[reference].0, corners[reference].1);
+                    if (b.1 - a.1).perp_dot(corner - a.1) < 0.0 {
+                        std::mem::swap(&mut a, &mut b);
+                    }
+                    points[a.0] = a.1;
+                    points[b.0] = b.1;
+                    segment_end[a.0] = b.0;
+                    has_start[a.0] = true;
+                    is_end[b.0] = true;
+                }
+            }
+        }
+
+        let mut visited = vec![false; edge_count];
+        let mut contours = Vec::new();
+        let mut trace = |start: usize, visited: &mut Vec<bool>| {
+            let mut vertices = Vec::new();
+            let mut edge = start;
+            let closed = loop {
+                vertices.push(points[edge]);
+                visited[edge] = true;
+                if !has_start[edge] {
+                    break false;
+                }
+                edge = segment_end[edge];
+                if edge == start {
+                    break true;
+                }
+            };
+            contours.push(Contour {
+                level,
+                polyline: BoxedPolyline2d::from(vertices),
+                closed,
+            });
+        };
+
+        // Open contours start on the boundary, everything left over is a closed loop
+        for edge in 0..edge_count {
+            if has_start[edge] && !is_end[edge] {
+                trace(edge, &mut visited);
+            }
+        }
+        for edge in 0..edge_count {
+            if has_start[edge] && !visited[edge] {
+                trace(edge, &mut visited);
+            }
+        }
+        contours
+    }
+
+    /// Get the id and interpolated crossing point of an edge of the cell at `(x, y)`
+    fn crossing(
+        &self,
+        x: usize,
+        y: usize,
+        edge: Edge,
+        level: f32,
+        horizontal_edges: usize,
+    ) -> (usize, Vec2) {
+        let ((x0, y0), (x1, y1), id) = match edge {
+            Edge::Bottom => ((x, y), (x + 1, y), y * (self.width - 1) + x),
+            Edge::Top => ((x, y + 1), (x + 1, y + 1), (y + 1) * (self.width - 1) + x),
+            Edge::Left => ((x, y), (x, y + 1), horizontal_edges + y * self.width + x),
+            Edge::Right => (
+                (x + 1, y),
+                (x + 1, y + 1),
+                horizontal_edges + y * self.width + x + 1,
+            ),
+        };
+
+        let (v0, v1) = (self.value(x0, y0), self.value(x1, y1));
+        let t = if (v1 - v0).abs() > f32::EPSILON {
+            ((level - v0) / (v1 - v0)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (id, self.position(x0, y0).lerp(self.position(x1, y1), t))
+    }
+}
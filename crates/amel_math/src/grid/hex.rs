@@ -0,0 +1,348 @@
+use crate::prelude::*;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A cell of a hexagonal grid in axial coordinates.
+///
+/// The third cube coordinate is implied by `q + r + s == 0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// The offsets to the six neighbours, in counterclockwise order
+    pub const DIRECTIONS: [Self; 6] = [
+        Self::new(1, 0),
+        Self::new(0, 1),
+        Self::new(-1, 1),
+        Self::new(-1, 0),
+        Self::new(0, -1),
+        Self::new(1, -1),
+    ];
+
+    /// The offsets to the six diagonal cells, which share a vertex but no edge with the cell
+    pub const DIAGONALS: [Self; 6] = [
+        Self::new(1, 1),
+        Self::new(-1, 2),
+        Self::new(-2, 1),
+        Self::new(-1, -1),
+        Self::new(1, -2),
+        Self::new(2, -1),
+    ];
+
+    #[inline(always)]
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// Create a new `Hex` from cube coordinates
+    #[inline(always)]
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Self {
+        debug_assert_eq!(q + r + s, 0, "cube coordinates must sum to zero");
+        Self::new(q, r)
+    }
+
+    /// Get the implied third cube coordinate
+    #[inline(always)]
+    pub const fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Get the cube coordinates `(q, r, s)`
+    #[inline(always)]
+    pub const fn cube(&self) -> IVec3 {
+        IVec3::new(self.q, self.r, self.s())
+    }
+
+    /// Get the neighbour in one of the six [`Hex::DIRECTIONS`]
+    #[inline(always)]
+    pub fn neighbour(self, direction: usize) -> Self {
+        self + Self::DIRECTIONS[direction % 6]
+    }
+
+    /// Get the six cells sharing an edge with this one
+    #[inline(always)]
+    pub fn neighbours(self) -> [Self; 6] {
+        Self::DIRECTIONS.map(|direction| self + direction)
+    }
+
+    /// Get the six cells sharing only a vertex with this one
+    #[inline(always)]
+    pub fn diagonals(self) -> [Self; 6] {
+        Self::DIAGONALS.map(|direction| self + direction)
+    }
+
+    /// Get the number of steps from the origin to this cell
+    #[inline(always)]
+    pub fn length(self) -> i32 {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    /// Get the number of steps between two cells
+    #[inline(always)]
+    pub fn distance(self, other: Self) -> i32 {
+        (self - other).length()
+    }
+
+    /// Round fractional axial coordinates to the cell containing them
+    pub fn round(fractional: Vec2) -> Self {
+        let cube = Vec3::new(fractional.x, fractional.y, -fractional.x - fractional.y);
+        let rounded = cube.round();
+        let diff = (rounded - cube).abs();
+
+        // Fix the coordinate with the largest rounding error so that they sum to zero
+        let (q, r) = if diff.x > diff.y && diff.x > diff.z {
+            (-rounded.y - rounded.z, rounded.y)
+        } else if diff.y > diff.z {
+            (rounded.x, -rounded.x - rounded.z)
+        } else {
+            (rounded.x, rounded.y)
+        };
+        Self::new(q as i32, r as i32)
+    }
+
+    /// Get the cells on the straight line from this cell to `other`, both included
+    pub fn line_to(self, other: Self) -> Vec<Self> {
+        let steps = self.distance(other);
+        let (start, end) = (self.as_vec2(), other.as_vec2());
+
+        // Nudge the line off the cell edges so that ties round consistently
+        let nudge = Vec2::new(1e-6, 2e-6);
+        (0..=steps)
+            .map(|i| {
+                let t = if steps == 0 {
+                    0.0
+                } else {
+                    i as f32 / steps as f32
+                };
+                Self::round(start.lerp(end, t) + nudge)
+            })
+            .collect()
+    }
+
+    /// Iterate over the cells exactly `radius` steps away, counterclockwise
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Self> {
+        let start = self + Self::DIRECTIONS[4] * radius as i32;
+        let sides = if radius == 0 { 1 } else { 6 };
+        (0..sides)
+            .flat_map(move |side| (0..radius.max(1)).map(move |step| (side, step)))
+            .scan(start, move |hex, (side, _)| {
+                let current = *hex;
+                *hex = *hex + Self::DIRECTIONS[side];
+                Some(current)
+            })
+    }
+
+    /// Iterate over the cells at most `radius` steps away, ring by ring starting with this cell
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |ring| self.ring(ring))
+    }
+
+    /// Get the number of cells within `radius` steps of a cell, including the cell itself
+    #[inline(always)]
+    pub const fn range_count(radius: u32) -> usize {
+        (3 * radius * (radius + 1) + 1) as usize
+    }
+
+    /// Rotate the cell by 60 degrees counterclockwise around the origin
+    #[inline(always)]
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.r, -self.s())
+    }
+
+    /// Rotate the cell by 60 degrees clockwise around the origin
+    #[inline(always)]
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.s(), -self.q)
+    }
+
+    #[inline(always)]
+    pub fn as_ivec2(self) -> IVec2 {
+        IVec2::new(self.q, self.r)
+    }
+
+    #[inline(always)]
+    pub fn as_vec2(self) -> Vec2 {
+        Vec2::new(self.q as f32, self.r as f32)
+    }
+}
+
+impl From<IVec2> for Hex {
+    fn from(axial: IVec2) -> Self {
+        Self::new(axial.x, axial.y)
+    }
+}
+
+impl From<Hex> for IVec2 {
+    fn from(hex: Hex) -> Self {
+        hex.as_ivec2()
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+/// How the cells of a hexagonal grid are oriented
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexOrientation {
+    /// Cells have a vertex at the top, and rows of cells are horizontal
+    #[default]
+    Pointy,
+    /// Cells have an edge at the top, and columns of cells are vertical
+    Flat,
+}
+
+impl HexOrientation {
+    /// Get the matrix mapping axial coordinates to positions for cells with a circumradius of `1.0`
+    #[inline(always)]
+    pub fn matrix(self) -> Mat2 {
+        let sqrt_3 = 3.0_f32.sqrt();
+        match self {
+            HexOrientation::Pointy => {
+                Mat2::from_cols(Vec2::new(sqrt_3, 0.0), Vec2::new(sqrt_3 / 2.0, 1.5))
+            }
+            HexOrientation::Flat => {
+                Mat2::from_cols(Vec2::new(1.5, sqrt_3 / 2.0), Vec2::new(0.0, sqrt_3))
+            }
+        }
+    }
+
+    /// Get the rotation to pass to [`RegularPolygon::vertices`] for a cell of this orientation
+    #[inline(always)]
+    pub fn rotation(self) -> f32 {
+        match self {
+            HexOrientation::Pointy => 0.0,
+            HexOrientation::Flat => FRAC_PI_6,
+        }
+    }
+}
+
+/// Maps the cells of a hexagonal grid to positions in 2D space
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexLayout {
+    pub orientation: HexOrientation,
+    /// The circumradius of every cell
+    pub size: f32,
+    /// The position of the center of [`Hex::ZERO`]
+    pub origin: Vec2,
+}
+
+impl Default for HexLayout {
+    fn default() -> Self {
+        Self::new(HexOrientation::Pointy, 1.0)
+    }
+}
+
+impl HexLayout {
+    /// Create a new `HexLayout` with cells of circumradius `size` centered on the origin
+    #[inline(always)]
+    pub const fn new(orientation: HexOrientation, size: f32) -> Self {
+        Self {
+            orientation,
+            size,
+            origin: Vec2::ZERO,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn with_origin(mut self, origin: Vec2) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Get the distance between the centers of two neighbouring cells
+    #[inline(always)]
+    pub fn spacing(&self) -> f32 {
+        self.size * 3.0_f32.sqrt()
+    }
+
+    /// Get the position of the center of `hex`
+    #[inline(always)]
+    pub fn hex_to_pixel(&self, hex: Hex) -> Vec2 {
+        self.origin + self.orientation.matrix() * hex.as_vec2() * self.size
+    }
+
+    /// Get the fractional axial coordinates of `position`
+    #[inline(always)]
+    pub fn pixel_to_fractional_hex(&self, position: Vec2) -> Vec2 {
+        self.orientation.matrix().inverse() * ((position - self.origin) / self.size)
+    }
+
+    /// Get the cell containing `position`
+    #[inline(always)]
+    pub fn pixel_to_hex(&self, position: Vec2) -> Hex {
+        Hex::round(self.pixel_to_fractional_hex(position))
+    }
+
+    /// Get the counterclockwise corners of `hex`
+    pub fn corners(&self, hex: Hex) -> [Vec2; 6] {
+        let center = self.hex_to_pixel(hex);
+        let mut corners = [center; 6];
+        for (corner, vertex) in corners
+            .iter_mut()
+            .zip(self.polygon().vertices(self.orientation.rotation()))
+        {
+            *corner += vertex;
+        }
+        corners
+    }
+
+    /// Get the shape shared by every cell
+    #[inline(always)]
+    pub fn polygon(&self) -> RegularPolygon {
+        RegularPolygon::new(self.size, 6)
+    }
+
+    /// Get the cell shape and the isometry placing it at the center of `hex`
+    #[inline(always)]
+    pub fn cell(&self, hex: Hex) -> (RegularPolygon, Isometry2d) {
+        (
+            self.polygon(),
+            Isometry2d::new(self.hex_to_pixel(hex), self.orientation.rotation()),
+        )
+    }
+
+    /// Get the cell shapes and isometries for every cell of `hexes`
+    pub fn cells(
+        &self,
+        hexes: impl IntoIterator<Item = Hex>,
+    ) -> impl Iterator<Item = (RegularPolygon, Isometry2d)> {
+        let layout = *self;
+        hexes.into_iter().map(move |hex| layout.cell(hex))
+    }
+}
@@ -0,0 +1,5 @@
+pub mod hex;
+pub mod triangle;
+
+pub use hex::*;
+pub use triangle::*;
@@ -0,0 +1,207 @@
+use crate::prelude::*;
+
+/// A cell of a triangular grid.
+///
+/// Each coordinate counts the grid lines crossed along one of three directions 120 degrees
+/// apart. Cells pointing up have `a + b + c == 2` and cells pointing down have
+/// `a + b + c == 1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriangleCell {
+    pub a: i32,
+    pub b: i32,
+    pub c: i32,
+}
+
+impl Default for TriangleCell {
+    fn default() -> Self {
+        Self::new(0, 1, 1)
+    }
+}
+
+impl TriangleCell {
+    #[inline(always)]
+    pub fn new(a: i32, b: i32, c: i32) -> Self {
+        debug_assert!(
+            matches!(a + b + c, 1 | 2),
+            "triangle coordinates must sum to 1 or 2"
+        );
+        Self { a, b, c }
+    }
+
+    /// Returns `true` if the cell has a vertex at the top
+    #[inline(always)]
+    pub fn points_up(&self) -> bool {
+        self.a + self.b + self.c == 2
+    }
+
+    /// Get the three cells sharing an edge with this one
+    #[inline(always)]
+    pub fn neighbours(self) -> [Self; 3] {
+        let Self { a, b, c } = self;
+        let step = if self.points_up() { -1 } else { 1 };
+        [
+            Self::new(a + step, b, c),
+            Self::new(a, b + step, c),
+            Self::new(a, b, c + step),
+        ]
+    }
+
+    /// Get the number of edges crossed on the way from this cell to `other`
+    #[inline(always)]
+    pub fn distance(self, other: Self) -> i32 {
+        (self.a - other.a).abs() + (self.b - other.b).abs() + (self.c - other.c).abs()
+    }
+
+    /// Iterate over the cells at most `radius` steps away, including this cell
+    pub fn range(self, radius: u32) -> impl Iterator<Item = Self> {
+        let r = radius as i32;
+        (self.a - r..=self.a + r).flat_map(move |a| {
+            (self.b - r..=self.b + r).flat_map(move |b| {
+                [1, 2].into_iter().filter_map(move |sum| {
+                    let cell = Self::new(a, b, sum - a - b);
+                    (self.distance(cell) <= r).then_some(cell)
+                })
+            })
+        })
+    }
+
+    /// Iterate over the cells exactly `radius` steps away
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Self> {
+        self.range(radius)
+            .filter(move |&cell| self.distance(cell) == radius as i32)
+    }
+
+    /// Get the cells at most `radius` steps away, sorted by distance starting with this cell
+    pub fn spiral(self, radius: u32) -> Vec<Self> {
+        let mut cells: Vec<_> = self.range(radius).collect();
+        cells.sort_by_key(|&cell| self.distance(cell));
+        cells
+    }
+}
+
+/// Maps the cells of a triangular grid to positions in 2D space.
+///
+/// Rows of cells are horizontal and alternate between pointing up and down.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriangleLayout {
+    /// The side length of every cell
+    pub size: f32,
+    /// The position of the corner shared by the cells around the origin
+    pub origin: Vec2,
+}
+
+impl Default for TriangleLayout {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl TriangleLayout {
+    /// Create a new `TriangleLayout` with cells of side length `size`
+    #[inline(always)]
+    pub const fn new(size: f32) -> Self {
+        Self {
+            size,
+            origin: Vec2::ZERO,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn with_origin(mut self, origin: Vec2) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Get the position of the center of `cell`
+    #[inline(always)]
+    pub fn cell_to_pixel(&self, cell: TriangleCell) -> Vec2 {
+        let sqrt_3 = 3.0_f32.sqrt();
+        let (a, b, c) = (cell.a as f32, cell.b as f32, cell.c as f32);
+        self.origin
+            + Vec2::new(
+                0.5 * a - 0.5 * c,
+                -sqrt_3 / 6.0 * a + sqrt_3 / 3.0 * b - sqrt_3 / 6.0 * c,
+            ) * self.size
+    }
+
+    /// Get the cell containing `position`
+    #[inline(always)]
+    pub fn pixel_to_cell(&self, position: Vec2) -> TriangleCell {
+        let sqrt_3 = 3.0_f32.sqrt();
+        let Vec2 { x, y } = (position - self.origin) / self.size;
+        TriangleCell::new(
+            (x - sqrt_3 / 3.0 * y).ceil() as i32,
+            (sqrt_3 * 2.0 / 3.0 * y).floor() as i32 + 1,
+            (-x - sqrt_3 / 3.0 * y).ceil() as i32,
+        )
+    }
+
+    /// Get the cells on the straight line between the centers of `start` and `end`, both included
+    pub fn line(&self, start: TriangleCell, end: TriangleCell) -> Vec<TriangleCell> {
+        let (from, to) = (self.cell_to_pixel(start), self.cell_to_pixel(end));
+
+        // Sample often enough to visit every crossed cell, nudged off the grid lines
+        let steps = start.distance(end) * 2;
+        let nudge = Vec2::new(1e-4, 2e-4) * self.size;
+        let mut cells = vec![start];
+        for i in 1..=steps {
+            let cell = self.pixel_to_cell(from.lerp(to, i as f32 / steps as f32) + nudge);
+            if cells.last() != Some(&cell) {
+                cells.push(cell);
+            }
+        }
+        if cells.last() != Some(&end) {
+            cells.push(end);
+        }
+        cells
+    }
+
+    /// Get the rotation to pass to [`RegularPolygon::vertices`] for `cell`
+    #[inline(always)]
+    pub fn rotation(&self, cell: TriangleCell) -> f32 {
+        if cell.points_up() {
+            0.0
+        } else {
+            PI
+        }
+    }
+
+    /// Get the counterclockwise corners of `cell`
+    pub fn corners(&self, cell: TriangleCell) -> [Vec2; 3] {
+        let center = self.cell_to_pixel(cell);
+        let mut corners = [center; 3];
+        for (corner, vertex) in corners
+            .iter_mut()
+            .zip(self.polygon().vertices(self.rotation(cell)))
+        {
+            *corner += vertex;
+        }
+        corners
+    }
+
+    /// Get the shape shared by every cell
+    #[inline(always)]
+    pub fn polygon(&self) -> RegularPolygon {
+        RegularPolygon::new(self.size / 3.0_f32.sqrt(), 3)
+    }
+
+    /// Get the cell shape and the isometry placing it at the center of `cell`
+    #[inline(always)]
+    pub fn cell(&self, cell: TriangleCell) -> (RegularPolygon, Isometry2d) {
+        (
+            self.polygon(),
+            Isometry2d::new(self.cell_to_pixel(cell), self.rotation(cell)),
+        )
+    }
+
+    /// Get the cell shapes and isometries for every cell of `cells`
+    pub fn cells(
+        &self,
+        cells: impl IntoIterator<Item = TriangleCell>,
+    ) -> impl Iterator<Item = (RegularPolygon, Isometry2d)> {
+        let layout = *self;
+        cells.into_iter().map(move |cell| layout.cell(cell))
+    }
+}
@@ -0,0 +1,225 @@
+use glam::{Mat3, Mat4, Quat, Vec2, Vec3};
+use std::ops::Mul;
+
+/// A rigid transform in 2D space, made of a rotation followed by a translation
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isometry2d {
+    /// The counterclockwise rotation in radians
+    pub rotation: f32,
+    pub translation: Vec2,
+}
+
+impl Default for Isometry2d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Isometry2d {
+    pub const IDENTITY: Self = Self {
+        rotation: 0.0,
+        translation: Vec2::ZERO,
+    };
+
+    #[inline(always)]
+    pub const fn new(translation: Vec2, rotation: f32) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn from_translation(translation: Vec2) -> Self {
+        Self::new(translation, 0.0)
+    }
+
+    #[inline(always)]
+    pub const fn from_xy(x: f32, y: f32) -> Self {
+        Self::from_translation(Vec2::new(x, y))
+    }
+
+    #[inline(always)]
+    pub const fn from_rotation(rotation: f32) -> Self {
+        Self::new(Vec2::ZERO, rotation)
+    }
+
+    /// Get the rotation as a unit vector, which can be applied with [`Vec2::rotate`]
+    #[inline(always)]
+    pub fn rotation_vector(&self) -> Vec2 {
+        Vec2::from_angle(self.rotation)
+    }
+
+    /// Rotate `vector` without translating it
+    #[inline(always)]
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        self.rotation_vector().rotate(vector)
+    }
+
+    #[inline(always)]
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Rotate `vector` by the inverse rotation
+    #[inline(always)]
+    pub fn inverse_transform_vector(&self, vector: Vec2) -> Vec2 {
+        Vec2::from_angle(-self.rotation).rotate(vector)
+    }
+
+    /// Transform `point` from world space into the local space of the isometry
+    #[inline(always)]
+    pub fn inverse_transform_point(&self, point: Vec2) -> Vec2 {
+        self.inverse_transform_vector(point - self.translation)
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self) -> Self {
+        Self::new(
+            -self.inverse_transform_vector(self.translation),
+            -self.rotation,
+        )
+    }
+
+    /// Get the affine matrix of the isometry
+    #[inline(always)]
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_scale_angle_translation(Vec2::ONE, self.rotation, self.translation)
+    }
+
+    /// Get the isometry as a 3D transform in the XY plane
+    #[inline(always)]
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_rotation_translation(
+            Quat::from_rotation_z(self.rotation),
+            self.translation.extend(0.0),
+        )
+    }
+}
+
+impl From<Vec2> for Isometry2d {
+    fn from(translation: Vec2) -> Self {
+        Self::from_translation(translation)
+    }
+}
+
+impl Mul for Isometry2d {
+    type Output = Self;
+
+    /// Compose two isometries, applying `rhs` first; 2D rotation angles add up
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.transform_point(rhs.translation),
+            self.rotation + rhs.rotation,
+        )
+    }
+}
+
+/// A rigid transform in 3D space, made of a rotation followed by a translation
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isometry3d {
+    pub rotation: Quat,
+    pub translation: Vec3,
+}
+
+impl Default for Isometry3d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Isometry3d {
+    pub const IDENTITY: Self = Self {
+        rotation: Quat::IDENTITY,
+        translation: Vec3::ZERO,
+    };
+
+    #[inline(always)]
+    pub const fn new(translation: Vec3, rotation: Quat) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn from_translation(translation: Vec3) -> Self {
+        Self::new(translation, Quat::IDENTITY)
+    }
+
+    #[inline(always)]
+    pub const fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        Self::from_translation(Vec3::new(x, y, z))
+    }
+
+    #[inline(always)]
+    pub const fn from_rotation(rotation: Quat) -> Self {
+        Self::new(Vec3::ZERO, rotation)
+    }
+
+    /// Rotate `vector` without translating it
+    #[inline(always)]
+    pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+        self.rotation * vector
+    }
+
+    #[inline(always)]
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.rotation * point + self.translation
+    }
+
+    /// Rotate `vector` by the inverse rotation
+    #[inline(always)]
+    pub fn inverse_transform_vector(&self, vector: Vec3) -> Vec3 {
+        self.rotation.inverse() * vector
+    }
+
+    /// Transform `point` from world space into the local space of the isometry
+    #[inline(always)]
+    pub fn inverse_transform_point(&self, point: Vec3) -> Vec3 {
+        self.rotation.inverse() * (point - self.translation)
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self::new(rotation * -self.translation, rotation)
+    }
+
+    #[inline(always)]
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_rotation_translation(self.rotation, self.translation)
+    }
+}
+
+impl From<Vec3> for Isometry3d {
+    fn from(translation: Vec3) -> Self {
+        Self::from_translation(translation)
+    }
+}
+
+impl From<Quat> for Isometry3d {
+    fn from(rotation: Quat) -> Self {
+        Self::from_rotation(rotation)
+    }
+}
+
+impl From<Isometry3d> for Mat4 {
+    fn from(isometry: Isometry3d) -> Self {
+        isometry.to_mat4()
+    }
+}
+
+impl Mul for Isometry3d {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.transform_point(rhs.translation),
+            self.rotation * rhs.rotation,
+        )
+    }
+}
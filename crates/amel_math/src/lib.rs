@@ -1,17 +1,32 @@
+pub mod bounding;
+pub mod contour;
+pub mod grid;
+pub mod isometry;
 pub mod matrix_stack;
+pub mod physics;
 pub mod primitive;
+pub mod query;
+pub mod ray;
 // pub mod random;
 
 pub mod prelude {
 
+    pub use super::bounding::*;
+    pub use super::contour::*;
+    pub use super::grid::*;
+    pub use super::isometry::*;
     pub use super::matrix_stack::*;
+    pub use super::physics::*;
     pub use super::primitive::prelude::*;
+    pub use super::query::*;
+    pub use super::ray::*;
     // pub use super::random::*;
 
     pub use std::f32::consts::*;
 
     pub use glam::{
-        BVec2, BVec3, BVec4, EulerRot, FloatExt, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat,
-        UVec2, UVec3, UVec4, Vec2, Vec2Swizzles, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles,
+        BVec2, BVec3, BVec4, DMat4, DQuat, DVec2, DVec3, DVec4, EulerRot, FloatExt, IVec2, IVec3,
+        IVec4, Mat2, Mat3, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec2Swizzles, Vec3, Vec3Swizzles,
+        Vec4, Vec4Swizzles,
     };
 }
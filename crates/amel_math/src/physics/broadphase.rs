@@ -0,0 +1,39 @@
+use crate::prelude::*;
+
+/// A sweep and prune broadphase over axis-aligned boxes.
+///
+/// Boxes are sorted along the X axis, so only boxes overlapping on that axis are ever compared.
+/// The pairs are reported in a deterministic order.
+#[derive(Clone, Debug, Default)]
+pub struct SweepAndPrune {
+    order: Vec<usize>,
+}
+
+impl SweepAndPrune {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Find every pair of overlapping boxes, as indices into `aabbs` with the smaller index first
+    pub fn overlapping_pairs(&mut self, aabbs: &[Aabb2d], pairs: &mut Vec<(usize, usize)>) {
+        pairs.clear();
+        if self.order.len() != aabbs.len() {
+            self.order.clear();
+            self.order.extend(0..aabbs.len());
+        }
+        // Sorting the previous order again is cheap as it rarely changes between steps
+        self.order
+            .sort_by(|&a, &b| aabbs[a].min.x.total_cmp(&aabbs[b].min.x).then(a.cmp(&b)));
+
+        for (i, &a) in self.order.iter().enumerate() {
+            for &b in &self.order[i + 1..] {
+                if aabbs[b].min.x > aabbs[a].max.x {
+                    break;
+                }
+                if aabbs[a].intersects(&aabbs[b]) {
+                    pairs.push((a.min(b), a.max(b)));
+                }
+            }
+        }
+    }
+}
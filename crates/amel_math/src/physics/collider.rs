@@ -0,0 +1,154 @@
+use crate::prelude::*;
+use crate::primitive::polyline::closest_point_on_polyline;
+
+/// Identifies a collider of a [`PhysicsWorld2d`](super::PhysicsWorld2d)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ColliderId(pub usize);
+
+/// The shape of a [`Collider`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColliderShape {
+    Circle(Circle),
+    Rectangle(Rectangle),
+    /// Any simple polygon, convex or not
+    Polygon(BoxedPolygon),
+}
+
+impl From<Circle> for ColliderShape {
+    fn from(circle: Circle) -> Self {
+        Self::Circle(circle)
+    }
+}
+
+impl From<Rectangle> for ColliderShape {
+    fn from(rectangle: Rectangle) -> Self {
+        Self::Rectangle(rectangle)
+    }
+}
+
+impl From<BoxedPolygon> for ColliderShape {
+    fn from(polygon: BoxedPolygon) -> Self {
+        Self::Polygon(polygon)
+    }
+}
+
+impl From<RegularPolygon> for ColliderShape {
+    fn from(polygon: RegularPolygon) -> Self {
+        Self::Polygon(polygon.into())
+    }
+}
+
+impl From<Triangle2d> for ColliderShape {
+    fn from(triangle: Triangle2d) -> Self {
+        Self::Polygon(triangle.into())
+    }
+}
+
+impl ColliderShape {
+    /// Returns `true` for a polygon without vertices, which nothing collides with
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, ColliderShape::Polygon(polygon) if polygon.vertices.is_empty())
+    }
+
+    /// Get the axis-aligned bounding box of the shape placed by `isometry`
+    pub fn aabb_2d(&self, isometry: Isometry2d) -> Aabb2d {
+        match self {
+            ColliderShape::Circle(circle) => circle.aabb_2d(isometry),
+            ColliderShape::Rectangle(rectangle) => rectangle.aabb_2d(isometry),
+            ColliderShape::Polygon(polygon) => polygon.aabb_2d(isometry),
+        }
+    }
+
+    /// Find how a circle of `radius` at the local `point` overlaps the shape.
+    ///
+    /// Returns the outward surface normal and the penetration depth, both in local space.
+    /// Polygons without vertices never overlap anything.
+    pub fn local_penetration(&self, point: Vec2, radius: f32) -> Option<(Vec2, f32)> {
+        let (surface, inside) = match self {
+            ColliderShape::Circle(circle) => {
+                let distance = point.length();
+                let normal = if distance > f32::EPSILON {
+                    point / distance
+                } else {
+                    Vec2::Y
+                };
+                let depth = circle.radius + radius - distance;
+                return (depth > 0.0).then_some((normal, depth));
+            }
+            ColliderShape::Rectangle(rectangle) => {
+                let polygon = BoxedPolygon::from(*rectangle);
+                (
+                    closest_point_on_polyline(point, &polygon.vertices, true)?,
+                    rectangle.local_contains_point(point),
+                )
+            }
+            ColliderShape::Polygon(polygon) => (
+                closest_point_on_polyline(point, &polygon.vertices, true)?,
+                polygon.local_contains_point(point),
+            ),
+        };
+
+        let offset = point - surface;
+        let distance = offset.length();
+        if distance <= f32::EPSILON {
+            return None;
+        }
+        if inside {
+            Some((-offset / distance, distance + radius))
+        } else {
+            (distance < radius).then(|| (offset / distance, radius - distance))
+        }
+    }
+}
+
+/// A static or kinematic shape that particles bounce off
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Collider {
+    pub shape: ColliderShape,
+    pub isometry: Isometry2d,
+    /// The fraction of the normal velocity kept when bouncing, from `0.0` to `1.0`
+    pub restitution: f32,
+    /// The fraction of the tangential velocity removed on contact, from `0.0` to `1.0`
+    pub friction: f32,
+}
+
+impl Collider {
+    /// Create a new `Collider` without bounce or friction
+    pub fn new(shape: impl Into<ColliderShape>, isometry: impl Into<Isometry2d>) -> Self {
+        Self {
+            shape: shape.into(),
+            isometry: isometry.into(),
+            restitution: 0.0,
+            friction: 0.0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_restitution(mut self, restitution: f32) -> Self {
+        self.restitution = restitution;
+        self
+    }
+
+    #[inline(always)]
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    #[inline(always)]
+    pub fn aabb_2d(&self) -> Aabb2d {
+        self.shape.aabb_2d(self.isometry)
+    }
+
+    /// Find how a circle of `radius` at `point` overlaps the collider.
+    ///
+    /// Returns the outward surface normal and the penetration depth in world space.
+    pub fn penetration(&self, point: Vec2, radius: f32) -> Option<(Vec2, f32)> {
+        self.shape
+            .local_penetration(self.isometry.inverse_transform_point(point), radius)
+            .map(|(normal, depth)| (self.isometry.transform_vector(normal), depth))
+    }
+}
@@ -0,0 +1,118 @@
+use super::{Particle, ParticleId};
+use crate::prelude::*;
+
+/// A positional constraint between particles, solved by projection
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Constraint {
+    /// Keep two particles `length` apart
+    Distance {
+        a: ParticleId,
+        b: ParticleId,
+        length: f32,
+        /// How much of the error is corrected per iteration, from `0.0` to `1.0`
+        stiffness: f32,
+    },
+    /// Keep a particle at a fixed position
+    Pin {
+        particle: ParticleId,
+        position: Vec2,
+    },
+    /// Keep the counterclockwise angle from `a` to `c` around `b` at `angle` radians.
+    ///
+    /// Only `a` and `c` are moved, `b` acts as the hinge.
+    Angle {
+        a: ParticleId,
+        b: ParticleId,
+        c: ParticleId,
+        angle: f32,
+        /// How much of the error is corrected per iteration, from `0.0` to `1.0`
+        stiffness: f32,
+    },
+}
+
+impl Constraint {
+    /// Create a [`Constraint::Distance`] keeping the current distance between two particles
+    pub fn distance(particles: &[Particle], a: ParticleId, b: ParticleId, stiffness: f32) -> Self {
+        Self::Distance {
+            a,
+            b,
+            length: particles[a.0].position.distance(particles[b.0].position),
+            stiffness,
+        }
+    }
+
+    /// Create a [`Constraint::Pin`] keeping a particle at its current position
+    pub fn pin(particles: &[Particle], particle: ParticleId) -> Self {
+        Self::Pin {
+            particle,
+            position: particles[particle.0].position,
+        }
+    }
+
+    /// Create a [`Constraint::Angle`] keeping the current angle between three particles
+    pub fn angle(
+        particles: &[Particle],
+        a: ParticleId,
+        b: ParticleId,
+        c: ParticleId,
+        stiffness: f32,
+    ) -> Self {
+        let hinge = particles[b.0].position;
+        Self::Angle {
+            a,
+            b,
+            c,
+            angle: (particles[a.0].position - hinge).angle_to(particles[c.0].position - hinge),
+            stiffness,
+        }
+    }
+
+    /// Move the particles towards satisfying the constraint
+    pub fn solve(&self, particles: &mut [Particle]) {
+        match *self {
+            Constraint::Distance {
+                a,
+                b,
+                length,
+                stiffness,
+            } => {
+                let (pa, pb) = (particles[a.0], particles[b.0]);
+                let total = pa.inverse_mass + pb.inverse_mass;
+                let delta = pb.position - pa.position;
+                let current = delta.length();
+                if total == 0.0 || current <= f32::EPSILON {
+                    return;
+                }
+                let correction = delta * ((current - length) / (current * total) * stiffness);
+                particles[a.0].position += correction * pa.inverse_mass;
+                particles[b.0].position -= correction * pb.inverse_mass;
+            }
+            Constraint::Pin { particle, position } => {
+                particles[particle.0].position = position;
+            }
+            Constraint::Angle {
+                a,
+                b,
+                c,
+                angle,
+                stiffness,
+            } => {
+                let (pa, pb, pc) = (particles[a.0], particles[b.0], particles[c.0]);
+                let total = pa.inverse_mass + pc.inverse_mass;
+                let (arm_a, arm_c) = (pa.position - pb.position, pc.position - pb.position);
+                if total == 0.0 || arm_a == Vec2::ZERO || arm_c == Vec2::ZERO {
+                    return;
+                }
+
+                // Wrap the error into -pi..pi so the constraint never takes the long way round
+                let error = (arm_a.angle_to(arm_c) - angle + PI).rem_euclid(TAU) - PI;
+                let correction = error * stiffness / total;
+                particles[a.0].position =
+                    pb.position + Vec2::from_angle(correction * pa.inverse_mass).rotate(arm_a);
+                particles[c.0].position =
+                    pb.position + Vec2::from_angle(-correction * pc.inverse_mass).rotate(arm_c);
+            }
+        }
+    }
+}
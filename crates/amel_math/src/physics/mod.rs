@@ -0,0 +1,11 @@
+pub mod broadphase;
+pub mod collider;
+pub mod constraint;
+pub mod particle;
+pub mod world;
+
+pub use broadphase::*;
+pub use collider::*;
+pub use constraint::*;
+pub use particle::*;
+pub use world::*;
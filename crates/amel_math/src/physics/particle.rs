@@ -0,0 +1,116 @@
+use crate::prelude::*;
+
+/// Identifies a particle of a [`PhysicsWorld2d`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ParticleId(pub usize);
+
+/// A point mass integrated with position Verlet.
+///
+/// The velocity is implied by the difference between the current and previous positions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Particle {
+    pub position: Vec2,
+    pub previous_position: Vec2,
+    /// Acceleration applied during the next step only, on top of gravity
+    pub acceleration: Vec2,
+    /// `0.0` for particles that never move on their own
+    pub inverse_mass: f32,
+    /// The collision radius, particles with a radius of `0.0` don't collide with each other
+    pub radius: f32,
+    /// Particles sharing a group don't collide with each other
+    pub group: Option<u32>,
+}
+
+impl Default for Particle {
+    fn default() -> Self {
+        Self::new(Vec2::ZERO)
+    }
+}
+
+impl Particle {
+    /// Create a new resting `Particle` with a mass of `1.0`
+    #[inline(always)]
+    pub const fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            previous_position: position,
+            acceleration: Vec2::ZERO,
+            inverse_mass: 1.0,
+            radius: 0.0,
+            group: None,
+        }
+    }
+
+    /// Set the mass of the particle, `f32::INFINITY` makes it static
+    #[inline(always)]
+    pub fn with_mass(mut self, mass: f32) -> Self {
+        self.inverse_mass = if mass > 0.0 { 1.0 / mass } else { 0.0 };
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_group(mut self, group: u32) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Make the particle static, so that only constraints and the user can move it
+    #[inline(always)]
+    pub const fn fixed(mut self) -> Self {
+        self.inverse_mass = 0.0;
+        self
+    }
+
+    /// Returns `true` if the particle is never moved by forces or collisions
+    #[inline(always)]
+    pub fn is_fixed(&self) -> bool {
+        self.inverse_mass == 0.0
+    }
+
+    /// Get the displacement over the last step
+    #[inline(always)]
+    pub fn displacement(&self) -> Vec2 {
+        self.position - self.previous_position
+    }
+
+    /// Get the velocity of the particle for a step of `dt`
+    #[inline(always)]
+    pub fn velocity(&self, dt: f32) -> Vec2 {
+        self.displacement() / dt
+    }
+
+    /// Set the velocity of the particle for a step of `dt`
+    #[inline(always)]
+    pub fn set_velocity(&mut self, velocity: Vec2, dt: f32) {
+        self.previous_position = self.position - velocity * dt;
+    }
+
+    /// Move the particle without giving it any velocity
+    #[inline(always)]
+    pub fn teleport(&mut self, position: Vec2) {
+        let displacement = self.displacement();
+        self.position = position;
+        self.previous_position = position - displacement;
+    }
+
+    /// Advance the particle by one Verlet step of `dt` under `gravity`, keeping `damping` of its velocity
+    #[inline(always)]
+    pub fn integrate(&mut self, gravity: Vec2, damping: f32, dt: f32) {
+        if self.is_fixed() {
+            self.previous_position = self.position;
+            self.acceleration = Vec2::ZERO;
+            return;
+        }
+        let displacement = self.displacement() * damping;
+        self.previous_position = self.position;
+        self.position += displacement + (gravity + self.acceleration) * dt * dt;
+        self.acceleration = Vec2::ZERO;
+    }
+}
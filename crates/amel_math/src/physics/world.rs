@@ -0,0 +1,407 @@
+use super::{Collider, ColliderId, Constraint, Particle, ParticleId, SweepAndPrune};
+use crate::prelude::*;
+
+/// A small 2D physics world of Verlet particles, constraints and colliders.
+///
+/// The world always advances in steps of `fixed_dt`, so the same inputs give the same results.
+///
+/// Colliders are kinematic: they can be moved between steps through
+/// [`PhysicsWorld2d::collider_mut`] and push particles out of their new place, but particles
+/// don't pick up their velocity. Particles and colliders share the broadphase, so only the
+/// particles near a collider are ever tested against it.
+#[derive(Clone, Debug)]
+pub struct PhysicsWorld2d {
+    pub particles: Vec<Particle>,
+    pub constraints: Vec<Constraint>,
+    pub colliders: Vec<Collider>,
+    pub gravity: Vec2,
+    /// The duration of a single step
+    pub fixed_dt: f32,
+    /// The number of constraint and collision relaxation passes per step
+    pub iterations: usize,
+    /// The fraction of the velocity kept every step
+    pub damping: f32,
+    /// The most steps [`PhysicsWorld2d::update`] takes at once, to avoid a spiral of death
+    pub max_steps: usize,
+    accumulator: f32,
+    next_group: u32,
+    broadphase: SweepAndPrune,
+    aabbs: Vec<Aabb2d>,
+    pairs: Vec<(usize, usize)>,
+    /// The particle and collider index of every potentially colliding pair for this step
+    collider_pairs: Vec<(usize, usize)>,
+    /// The last collider each particle touched during the current step, with its normal
+    contacts: Vec<Option<(usize, Vec2)>>,
+    /// The displacement of each particle before any correction during the current step
+    displacements: Vec<Vec2>,
+}
+
+impl Default for PhysicsWorld2d {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhysicsWorld2d {
+    /// Create a new empty world stepping at 60Hz with a downward gravity of `9.81`
+    pub fn new() -> Self {
+        Self {
+            particles: Vec::new(),
+            constraints: Vec::new(),
+            colliders: Vec::new(),
+            gravity: Vec2::new(0.0, -9.81),
+            fixed_dt: 1.0 / 60.0,
+            iterations: 8,
+            damping: 0.999,
+            max_steps: 8,
+            accumulator: 0.0,
+            next_group: 0,
+            broadphase: SweepAndPrune::new(),
+            aabbs: Vec::new(),
+            pairs: Vec::new(),
+            collider_pairs: Vec::new(),
+            contacts: Vec::new(),
+            displacements: Vec::new(),
+        }
+    }
+
+    pub fn with_gravity(mut self, gravity: Vec2) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    pub fn with_fixed_dt(mut self, fixed_dt: f32) -> Self {
+        assert!(fixed_dt > 0.0, "fixed dt must be positive");
+        self.fixed_dt = fixed_dt;
+        self
+    }
+
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    pub fn add_particle(&mut self, particle: Particle) -> ParticleId {
+        self.particles.push(particle);
+        ParticleId(self.particles.len() - 1)
+    }
+
+    #[inline(always)]
+    pub fn particle(&self, id: ParticleId) -> &Particle {
+        &self.particles[id.0]
+    }
+
+    #[inline(always)]
+    pub fn particle_mut(&mut self, id: ParticleId) -> &mut Particle {
+        &mut self.particles[id.0]
+    }
+
+    /// Get the velocity of a particle
+    #[inline(always)]
+    pub fn velocity(&self, id: ParticleId) -> Vec2 {
+        self.particles[id.0].velocity(self.fixed_dt)
+    }
+
+    /// Set the velocity of a particle
+    #[inline(always)]
+    pub fn set_velocity(&mut self, id: ParticleId, velocity: Vec2) {
+        self.particles[id.0].set_velocity(velocity, self.fixed_dt);
+    }
+
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Connect two particles with a [`Constraint::Distance`] at their current distance
+    pub fn connect(&mut self, a: ParticleId, b: ParticleId, stiffness: f32) {
+        self.constraints
+            .push(Constraint::distance(&self.particles, a, b, stiffness));
+    }
+
+    /// Pin a particle at its current position
+    pub fn pin(&mut self, particle: ParticleId) {
+        self.constraints
+            .push(Constraint::pin(&self.particles, particle));
+    }
+
+    pub fn add_collider(&mut self, collider: Collider) -> ColliderId {
+        self.colliders.push(collider);
+        ColliderId(self.colliders.len() - 1)
+    }
+
+    #[inline(always)]
+    pub fn collider_mut(&mut self, id: ColliderId) -> &mut Collider {
+        &mut self.colliders[id.0]
+    }
+
+    /// Get a collision group no other particle uses yet
+    pub fn new_group(&mut self) -> u32 {
+        self.next_group += 1;
+        self.next_group
+    }
+
+    /// Add a chain of particles through `points`, linked by distance constraints.
+    ///
+    /// A `bending_stiffness` above `0.0` also adds angle constraints resisting bending,
+    /// which turns a rope into a rod. The particles of the chain don't collide with each other.
+    pub fn add_chain(
+        &mut self,
+        points: impl IntoIterator<Item = Vec2>,
+        radius: f32,
+        stiffness: f32,
+        bending_stiffness: f32,
+    ) -> Vec<ParticleId> {
+        let group = self.new_group();
+        let ids: Vec<ParticleId> = points
+            .into_iter()
+            .map(|point| {
+                self.add_particle(Particle::new(point).with_radius(radius).with_group(group))
+            })
+            .collect();
+
+        for pair in ids.windows(2) {
+            self.connect(pair[0], pair[1], stiffness);
+        }
+        if bending_stiffness > 0.0 {
+            for triple in ids.windows(3) {
+                self.constraints.push(Constraint::angle(
+                    &self.particles,
+                    triple[0],
+                    triple[1],
+                    triple[2],
+                    bending_stiffness,
+                ));
+            }
+        }
+        ids
+    }
+
+    /// Add a soft body with a particle at every vertex of `polygon` placed by `isometry`.
+    ///
+    /// The outline is linked by distance constraints and braced across the center,
+    /// so the body springs back into shape after being squashed.
+    pub fn add_soft_body(
+        &mut self,
+        polygon: &BoxedPolygon,
+        isometry: impl Into<Isometry2d>,
+        radius: f32,
+        stiffness: f32,
+    ) -> Vec<ParticleId> {
+        let isometry = isometry.into();
+        let group = self.new_group();
+        let ids: Vec<ParticleId> = polygon
+            .vertices
+            .iter()
+            .map(|&vertex| {
+                let particle = Particle::new(isometry.transform_point(vertex))
+                    .with_radius(radius)
+                    .with_group(group);
+                self.add_particle(particle)
+            })
+            .collect();
+
+        let count = ids.len();
+        for i in 0..count {
+            self.connect(ids[i], ids[(i + 1) % count], stiffness);
+        }
+        // Brace every vertex to the ones roughly opposite it
+        for i in 0..count {
+            for offset in 2..=count / 2 {
+                let j = (i + offset) % count;
+                if offset * 2 < count || i < j {
+                    self.connect(ids[i], ids[j], stiffness);
+                }
+            }
+        }
+        ids
+    }
+
+    /// Add a cloth of `resolution` particles spanning `size` from `origin` along +X and -Y.
+    ///
+    /// The particles are returned row by row, starting at `origin`.
+    pub fn add_cloth(
+        &mut self,
+        origin: Vec2,
+        size: Vec2,
+        resolution: UVec2,
+        stiffness: f32,
+    ) -> Vec<ParticleId> {
+        assert!(
+            resolution.x > 1 && resolution.y > 1,
+            "cloth needs at least 2 particles on each axis"
+        );
+        let (width, height) = (resolution.x as usize, resolution.y as usize);
+        let spacing = size / (resolution - UVec2::ONE).as_vec2();
+        let group = self.new_group();
+
+        let mut ids = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let position = origin + Vec2::new(x as f32, -(y as f32)) * spacing;
+                ids.push(self.add_particle(Particle::new(position).with_group(group)));
+            }
+        }
+        for y in 0..height {
+            for x in 0..width {
+                let id = ids[y * width + x];
+                if x + 1 < width {
+                    self.connect(id, ids[y * width + x + 1], stiffness);
+                }
+                if y + 1 < height {
+                    self.connect(id, ids[(y + 1) * width + x], stiffness);
+                }
+            }
+        }
+        ids
+    }
+
+    /// Advance the world by `elapsed` seconds in fixed steps, returning the number of steps taken.
+    ///
+    /// Time left over is carried to the next call.
+    pub fn update(&mut self, elapsed: f32) -> usize {
+        self.accumulator += elapsed;
+        let mut steps = 0;
+        while self.accumulator >= self.fixed_dt && steps < self.max_steps {
+            self.step();
+            self.accumulator -= self.fixed_dt;
+            steps += 1;
+        }
+        if steps == self.max_steps {
+            self.accumulator = self.accumulator.min(self.fixed_dt);
+        }
+        steps
+    }
+
+    /// Advance the world by a single step of `fixed_dt`
+    pub fn step(&mut self) {
+        for particle in &mut self.particles {
+            particle.integrate(self.gravity, self.damping, self.fixed_dt);
+        }
+
+        self.displacements.clear();
+        self.displacements
+            .extend(self.particles.iter().map(Particle::displacement));
+        self.find_pairs();
+        self.contacts.clear();
+        self.contacts.resize(self.particles.len(), None);
+        for _ in 0..self.iterations {
+            for constraint in &self.constraints {
+                constraint.solve(&mut self.particles);
+            }
+            self.solve_particle_collisions();
+            self.solve_collider_collisions();
+        }
+        // Apply bounce and friction once the positions have settled
+        self.apply_contact_response();
+    }
+
+    /// Gather the potentially colliding particle pairs and particle-collider pairs for this step
+    fn find_pairs(&mut self) {
+        self.aabbs.clear();
+        self.aabbs.extend(self.particles.iter().map(|particle| {
+            // Pad by the displacement so fast particles still find their pairs
+            let padding = particle.radius + particle.displacement().abs().max_element();
+            Aabb2d::new(particle.position, Vec2::splat(padding))
+        }));
+        // Colliders follow the particles, so their boxes start at `particles.len()`. Empty
+        // colliders get a box that overlaps nothing, keeping the indices of the others intact
+        self.aabbs.extend(self.colliders.iter().map(|collider| {
+            if collider.shape.is_empty() {
+                Aabb2d {
+                    min: Vec2::INFINITY,
+                    max: Vec2::NEG_INFINITY,
+                }
+            } else {
+                collider.aabb_2d()
+            }
+        }));
+        self.broadphase
+            .overlapping_pairs(&self.aabbs, &mut self.pairs);
+
+        let particles = &self.particles;
+        let count = particles.len();
+        self.collider_pairs.clear();
+        self.collider_pairs.extend(
+            self.pairs
+                .iter()
+                .filter(|&&(a, b)| a < count && b >= count && !particles[a].is_fixed())
+                .map(|&(particle, collider)| (particle, collider - count)),
+        );
+        self.pairs.retain(|&(a, b)| {
+            if b >= count {
+                return false;
+            }
+            let (a, b) = (&particles[a], &particles[b]);
+            a.radius > 0.0
+                && b.radius > 0.0
+                && !(a.is_fixed() && b.is_fixed())
+                && (a.group.is_none() || a.group != b.group)
+        });
+    }
+
+    fn solve_particle_collisions(&mut self) {
+        for &(a, b) in &self.pairs {
+            let (pa, pb) = (self.particles[a], self.particles[b]);
+            let delta = pb.position - pa.position;
+            let distance = delta.length();
+            let overlap = pa.radius + pb.radius - distance;
+            if overlap <= 0.0 {
+                continue;
+            }
+            let normal = if distance > f32::EPSILON {
+                delta / distance
+            } else {
+                Vec2::Y
+            };
+            let total = pa.inverse_mass + pb.inverse_mass;
+            let correction = normal * (overlap / total);
+            self.particles[a].position -= correction * pa.inverse_mass;
+            self.particles[b].position += correction * pb.inverse_mass;
+        }
+    }
+
+    fn solve_collider_collisions(&mut self) {
+        for &(index, collider) in &self.collider_pairs {
+            let particle = &mut self.particles[index];
+            if let Some((normal, depth)) =
+                self.colliders[collider].penetration(particle.position, particle.radius)
+            {
+                particle.position += normal * depth;
+                self.contacts[index] = Some((collider, normal));
+            }
+        }
+    }
+
+    /// Bounce and slow down the particles that touched a collider during this step
+    fn apply_contact_response(&mut self) {
+        for ((particle, contact), incoming) in self
+            .particles
+            .iter_mut()
+            .zip(&self.contacts)
+            .zip(&self.displacements)
+        {
+            let Some((index, normal)) = *contact else {
+                continue;
+            };
+            let collider = &self.colliders[index];
+
+            // Bounce with the velocity the particle hit the collider with, since pushing it
+            // out has already cancelled most of it, and damp the current tangential velocity
+            let displacement = particle.displacement();
+            let tangent_part = displacement - normal * displacement.dot(normal);
+            let incoming = incoming.dot(normal);
+            let normal_part = if incoming < 0.0 {
+                normal * (-incoming * collider.restitution)
+            } else {
+                normal * displacement.dot(normal).max(0.0)
+            };
+            particle.previous_position =
+                particle.position - (normal_part + tangent_part * (1.0 - collider.friction));
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod circle;
 pub mod ellipse;
+pub mod offset;
+pub mod polygon;
 pub mod polyline2d;
 pub mod rectangle;
 pub mod regular_polygon;
@@ -8,6 +10,8 @@ pub mod triangle2d;
 
 pub use circle::*;
 pub use ellipse::*;
+pub use offset::*;
+pub use polygon::*;
 pub use polyline2d::*;
 pub use rectangle::*;
 pub use regular_polygon::*;
@@ -0,0 +1,477 @@
+use super::BoxedPolygon;
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// How the offset edges are connected at the corners that open up a gap.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JoinStyle {
+    /// Extend both edges until they meet, falling back to [`JoinStyle::Bevel`]
+    /// when the miter would be longer than [`OffsetOptions::miter_limit`]
+    #[default]
+    Miter,
+    /// Connect the edges with a circular arc
+    Round,
+    /// Connect the edges with a straight line
+    Bevel,
+}
+
+/// How the ends of an open polyline are closed when it is offset on both sides.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EndCap {
+    /// End exactly at the end points
+    #[default]
+    Butt,
+    /// Extend past the end points by the offset distance
+    Square,
+    /// Close the ends with a half circle
+    Round,
+}
+
+/// Options used by the polygon and polyline offsetting functions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OffsetOptions {
+    pub join: JoinStyle,
+    pub end_cap: EndCap,
+    /// The largest allowed ratio between the miter length and the offset distance
+    pub miter_limit: f32,
+    /// The number of segments used for a full circle by round joins and caps
+    pub resolution: usize,
+}
+
+impl Default for OffsetOptions {
+    fn default() -> Self {
+        Self {
+            join: JoinStyle::Miter,
+            end_cap: EndCap::Butt,
+            miter_limit: 2.0,
+            resolution: 32,
+        }
+    }
+}
+
+impl OffsetOptions {
+    #[inline]
+    pub fn new(join: JoinStyle) -> Self {
+        Self {
+            join,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub const fn join(mut self, join: JoinStyle) -> Self {
+        self.join = join;
+        self
+    }
+
+    #[inline]
+    pub const fn end_cap(mut self, end_cap: EndCap) -> Self {
+        self.end_cap = end_cap;
+        self
+    }
+
+    #[inline]
+    pub const fn miter_limit(mut self, miter_limit: f32) -> Self {
+        self.miter_limit = miter_limit;
+        self
+    }
+
+    #[inline]
+    pub const fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+/// Offset a set of closed polygons by `delta`.
+///
+/// Outer boundaries are expected to be counterclockwise and holes clockwise, so a positive
+/// `delta` grows the filled area and a negative one shrinks it. Overlaps and self-intersections
+/// created by the offset are resolved, and the result follows the same winding convention.
+pub fn offset_polygons(
+    polygons: &[BoxedPolygon],
+    delta: f32,
+    options: OffsetOptions,
+) -> Vec<BoxedPolygon> {
+    let contours: Vec<Vec<Vec2>> = polygons
+        .iter()
+        .map(|polygon| dedup_points(&polygon.vertices, true))
+        .filter(|points| points.len() > 2)
+        .map(|points| {
+            if delta == 0.0 {
+                points
+            } else {
+                raw_offset(&points, delta, &options, true)
+            }
+        })
+        .collect();
+
+    resolve_contours(&contours)
+        .into_iter()
+        .map(BoxedPolygon::from)
+        .collect()
+}
+
+/// Offset an open polyline by `delta` on one side.
+///
+/// Positive values offset to the left of the direction of travel, negative values to the
+/// right. Loops created where the offset folds back on itself are cut away.
+pub fn offset_polyline(points: &[Vec2], delta: f32, options: OffsetOptions) -> Vec<Vec2> {
+    let points = dedup_points(points, false);
+    if points.len() < 2 || delta == 0.0 {
+        return points;
+    }
+
+    // `raw_offset` offsets to the right of the direction of travel
+    remove_open_loops(&raw_offset(&points, -delta, &options, false))
+}
+
+/// Offset an open polyline by `half_width` on both sides, returning the closed outline.
+///
+/// The ends are closed with [`OffsetOptions::end_cap`]. Where the polyline crosses itself
+/// the outline is merged into a single region, which may contain clockwise holes.
+pub fn offset_polyline_outline(
+    points: &[Vec2],
+    half_width: f32,
+    options: OffsetOptions,
+) -> Vec<BoxedPolygon> {
+    let points = dedup_points(points, false);
+    let half_width = half_width.abs();
+    if points.len() < 2 || half_width == 0.0 {
+        return Vec::new();
+    }
+
+    // Run along the right side, around the end, back along the left side and around the start
+    let reversed: Vec<Vec2> = points.iter().rev().copied().collect();
+    let mut contour = raw_offset(&points, half_width, &options, false);
+    push_cap(&mut contour, &points, half_width, &options);
+    contour.extend(raw_offset(&reversed, half_width, &options, false));
+    push_cap(&mut contour, &reversed, half_width, &options);
+
+    resolve_contours(&[dedup_points(&contour, true)])
+        .into_iter()
+        .map(BoxedPolygon::from)
+        .collect()
+}
+
+/// Remove consecutive duplicate points, including the closing duplicate when `closed`
+fn dedup_points(points: &[Vec2], closed: bool) -> Vec<Vec2> {
+    let mut result: Vec<Vec2> = Vec::with_capacity(points.len());
+    for &point in points {
+        if result
+            .last()
+            .is_none_or(|last| last.distance_squared(point) > 1e-12)
+        {
+            result.push(point);
+        }
+    }
+    while closed
+        && result.len() > 1
+        && result[0].distance_squared(result[result.len() - 1]) <= 1e-12
+    {
+        result.pop();
+    }
+    result
+}
+
+#[inline(always)]
+fn right_normal(direction: Vec2) -> Vec2 {
+    Vec2::new(direction.y, -direction.x)
+}
+
+/// Build the offset path to the right of `points` by `delta`, without resolving intersections
+fn raw_offset(points: &[Vec2], delta: f32, options: &OffsetOptions, closed: bool) -> Vec<Vec2> {
+    let len = points.len();
+    let mut result = Vec::with_capacity(len * 2);
+
+    let direction = |i: usize| (points[(i + 1) % len] - points[i]).normalize();
+
+    if !closed {
+        result.push(points[0] + right_normal(direction(0)) * delta);
+    }
+
+    let corners = if closed { 0..len } else { 1..len - 1 };
+    for i in corners {
+        let d0 = direction((i + len - 1) % len);
+        let d1 = direction(i);
+        push_join(&mut result, points[i], d0, d1, delta, options);
+    }
+
+    if !closed {
+        result.push(points[len - 1] + right_normal(direction(len - 2)) * delta);
+    }
+    result
+}
+
+/// Push the offset vertices for the corner at `point` between directions `d0` and `d1`
+fn push_join(
+    result: &mut Vec<Vec2>,
+    point: Vec2,
+    d0: Vec2,
+    d1: Vec2,
+    delta: f32,
+    options: &OffsetOptions,
+) {
+    let n0 = right_normal(d0);
+    let n1 = right_normal(d1);
+    let a = point + n0 * delta;
+    let b = point + n1 * delta;
+
+    let cross = d0.perp_dot(d1);
+    let dot = d0.dot(d1);
+
+    // Nearly straight, the two offset points coincide
+    if cross.abs() < 1e-6 && dot > 0.0 {
+        result.push(a);
+        return;
+    }
+
+    // The corner folds towards the offset side, so the edges overlap instead of leaving
+    // a gap. Going back through the original vertex keeps the winding numbers correct,
+    // and the resulting loop is removed when the contours are resolved.
+    if cross * delta < 0.0 {
+        result.extend([a, point, b]);
+        return;
+    }
+
+    match options.join {
+        JoinStyle::Bevel => result.extend([a, b]),
+        JoinStyle::Miter => {
+            let bisector = (n0 + n1).normalize_or_zero();
+            let cos_half = bisector.dot(n0);
+            if cos_half > f32::EPSILON && 1.0 / cos_half <= options.miter_limit {
+                result.push(point + bisector * (delta / cos_half));
+            } else {
+                result.extend([a, b]);
+            }
+        }
+        JoinStyle::Round => {
+            let angle = n0.perp_dot(n1).atan2(n0.dot(n1));
+            push_arc(result, point, n0 * delta, angle, options.resolution);
+        }
+    }
+}
+
+/// Push an arc around `center`, starting at `center + radius` and sweeping `angle` radians
+fn push_arc(result: &mut Vec<Vec2>, center: Vec2, radius: Vec2, angle: f32, resolution: usize) {
+    let steps = ((angle.abs() / TAU) * resolution.max(3) as f32)
+        .ceil()
+        .max(1.0) as usize;
+    for i in 0..=steps {
+        let rotation = Vec2::from_angle(angle * i as f32 / steps as f32);
+        result.push(center + rotation.rotate(radius));
+    }
+}
+
+/// Push the cap at the end of `points` onto an outline running along its right side
+fn push_cap(result: &mut Vec<Vec2>, points: &[Vec2], half_width: f32, options: &OffsetOptions) {
+    let end = points[points.len() - 1];
+    let direction = (end - points[points.len() - 2]).normalize();
+    let normal = right_normal(direction) * half_width;
+
+    match options.end_cap {
+        EndCap::Butt => {}
+        EndCap::Square => {
+            let extension = direction * half_width;
+            result.extend([end + normal + extension, end - normal + extension]);
+        }
+        EndCap::Round => push_arc(result, end, normal, PI, options.resolution),
+    }
+}
+
+/// Cut out the loops of an open path that crosses itself
+fn remove_open_loops(points: &[Vec2]) -> Vec<Vec2> {
+    let mut points = points.to_vec();
+    let mut result = Vec::with_capacity(points.len());
+
+    let mut i = 0;
+    while i + 1 < points.len() {
+        result.push(points[i]);
+
+        // Jump to the furthest segment that crosses this one
+        let mut next = i + 1;
+        for j in (i + 2..points.len() - 1).rev() {
+            if let Some((t, _, point)) =
+                segment_intersection(points[i], points[i + 1], points[j], points[j + 1])
+            {
+                if t > 1e-6 {
+                    // Continue along the crossing segment, starting from the crossing point
+                    points[j] = point;
+                    next = j;
+                    break;
+                }
+            }
+        }
+        i = next;
+    }
+
+    result.extend(points.last());
+    dedup_points(&result, false)
+}
+
+/// Get the parameters along both segments and the point where `a0 -> a1` crosses `b0 -> b1`
+fn segment_intersection(a0: Vec2, a1: Vec2, b0: Vec2, b1: Vec2) -> Option<(f32, f32, Vec2)> {
+    let r = a1 - a0;
+    let s = b1 - b0;
+    let denominator = r.perp_dot(s);
+    if denominator.abs() <= f32::EPSILON * r.length() * s.length() {
+        return None;
+    }
+
+    let qp = b0 - a0;
+    let t = qp.perp_dot(s) / denominator;
+    let u = qp.perp_dot(r) / denominator;
+    const TOLERANCE: f32 = 1e-6;
+    if (-TOLERANCE..=1.0 + TOLERANCE).contains(&t) && (-TOLERANCE..=1.0 + TOLERANCE).contains(&u) {
+        let t = t.clamp(0.0, 1.0);
+        let u = u.clamp(0.0, 1.0);
+        Some((t, u, a0 + r * t))
+    } else {
+        None
+    }
+}
+
+/// Get the winding number of `point` with respect to all `contours`
+fn winding_number(point: Vec2, contours: &[Vec<Vec2>]) -> i32 {
+    let mut winding = 0;
+    for contour in contours {
+        for (i, &a) in contour.iter().enumerate() {
+            let b = contour[(i + 1) % contour.len()];
+            let side = (b - a).perp_dot(point - a);
+            if a.y <= point.y {
+                if b.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+    winding
+}
+
+/// Merge closed contours into the boundary of the region with a positive winding number.
+///
+/// Every edge is split where it crosses another one, only the pieces that separate a filled
+/// region on their left from an empty one on their right are kept, and those are chained
+/// back into closed loops.
+fn resolve_contours(contours: &[Vec<Vec2>]) -> Vec<Vec<Vec2>> {
+    let mut edges = Vec::new();
+    for (c, contour) in contours.iter().enumerate() {
+        for i in 0..contour.len() {
+            edges.push((c, i, contour[i], contour[(i + 1) % contour.len()]));
+        }
+    }
+    if edges.is_empty() {
+        return Vec::new();
+    }
+
+    let (min, max) = edges.iter().fold(
+        (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+        |(min, max), &(_, _, a, _)| (min.min(a), max.max(a)),
+    );
+    let epsilon = (max - min).length().max(1.0) * 1e-5;
+
+    let adjacent = |e0: &(usize, usize, Vec2, Vec2), e1: &(usize, usize, Vec2, Vec2)| {
+        let len = contours[e0.0].len();
+        e0.0 == e1.0 && ((e0.1 + 1) % len == e1.1 || (e1.1 + 1) % len == e0.1)
+    };
+
+    // Split every edge at its intersections, snapping to existing vertices so that the
+    // pieces share bit-identical end points
+    let mut splits: Vec<Vec<(f32, Vec2)>> = vec![Vec::new(); edges.len()];
+    for i in 0..edges.len() {
+        for j in i + 1..edges.len() {
+            let (ei, ej) = (&edges[i], &edges[j]);
+            if adjacent(ei, ej) {
+                continue;
+            }
+            if let Some((t, u, point)) = segment_intersection(ei.2, ei.3, ej.2, ej.3) {
+                let point = if t <= 1e-6 {
+                    ei.2
+                } else if t >= 1.0 - 1e-6 {
+                    ei.3
+                } else if u <= 1e-6 {
+                    ej.2
+                } else if u >= 1.0 - 1e-6 {
+                    ej.3
+                } else {
+                    point
+                };
+                splits[i].push((t, point));
+                splits[j].push((u, point));
+            }
+        }
+    }
+
+    let mut pieces = Vec::new();
+    for (edge, mut split) in edges.iter().zip(splits) {
+        split.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut points = vec![edge.2];
+        points.extend(split.into_iter().map(|(_, point)| point));
+        points.push(edge.3);
+        points.dedup();
+
+        for piece in points.windows(2) {
+            let (a, b) = (piece[0], piece[1]);
+            let direction = b - a;
+            if direction.length_squared() <= epsilon * epsilon * 1e-4 {
+                continue;
+            }
+            let middle = (a + b) / 2.0;
+            let left = direction.normalize().perp() * epsilon;
+            if winding_number(middle + left, contours) > 0
+                && winding_number(middle - left, contours) <= 0
+            {
+                pieces.push((a, b));
+            }
+        }
+    }
+
+    chain_pieces(&pieces, epsilon)
+}
+
+/// Chain directed pieces that share end points into closed loops
+fn chain_pieces(pieces: &[(Vec2, Vec2)], epsilon: f32) -> Vec<Vec<Vec2>> {
+    let key = |point: Vec2| (point.x.to_bits(), point.y.to_bits());
+
+    let mut outgoing: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for (i, &(a, _)) in pieces.iter().enumerate() {
+        outgoing.entry(key(a)).or_default().push(i);
+    }
+
+    let mut used = vec![false; pieces.len()];
+    let mut loops = Vec::new();
+    for start in 0..pieces.len() {
+        if used[start] {
+            continue;
+        }
+
+        let mut contour = Vec::new();
+        let mut current = start;
+        let closed = loop {
+            used[current] = true;
+            let (a, b) = pieces[current];
+            contour.push(a);
+            if key(b) == key(pieces[start].0) {
+                break true;
+            }
+            let next = outgoing
+                .get(&key(b))
+                .and_then(|candidates| candidates.iter().copied().find(|&i| !used[i]));
+            match next {
+                Some(next) => current = next,
+                None => break false,
+            }
+        };
+
+        let contour = dedup_points(&contour, true);
+        if closed
+            && contour.len() > 2
+            && BoxedPolygon::new(contour.iter().copied()).area() > epsilon * epsilon
+        {
+            loops.push(contour);
+        }
+    }
+    loops
+}
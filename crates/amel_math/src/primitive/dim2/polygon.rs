@@ -0,0 +1,120 @@
+use super::{OffsetOptions, Rectangle, RegularPolygon, Triangle2d};
+use super::{Primitive2d, WindingOrder};
+use crate::prelude::*;
+
+/// A closed polygon in 2D space with a number of vertices only known at runtime.
+///
+/// The last vertex is implicitly connected back to the first one.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxedPolygon {
+    /// The vertices of the polygon
+    pub vertices: Box<[Vec2]>,
+}
+impl Primitive2d for BoxedPolygon {}
+
+impl FromIterator<Vec2> for BoxedPolygon {
+    fn from_iter<I: IntoIterator<Item = Vec2>>(iter: I) -> Self {
+        Self {
+            vertices: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl From<Vec<Vec2>> for BoxedPolygon {
+    fn from(vertices: Vec<Vec2>) -> Self {
+        Self {
+            vertices: vertices.into_boxed_slice(),
+        }
+    }
+}
+
+impl From<Triangle2d> for BoxedPolygon {
+    fn from(triangle: Triangle2d) -> Self {
+        Self::new(triangle.vertices)
+    }
+}
+
+impl From<Rectangle> for BoxedPolygon {
+    /// Returns the counterclockwise corners of the rectangle centered at the origin
+    fn from(rectangle: Rectangle) -> Self {
+        let half_size = rectangle.size / 2.0;
+        Self::new([
+            Vec2::new(-half_size.x, -half_size.y),
+            Vec2::new(half_size.x, -half_size.y),
+            Vec2::new(half_size.x, half_size.y),
+            Vec2::new(-half_size.x, half_size.y),
+        ])
+    }
+}
+
+impl From<RegularPolygon> for BoxedPolygon {
+    fn from(polygon: RegularPolygon) -> Self {
+        Self::new(polygon.vertices(0.0))
+    }
+}
+
+impl BoxedPolygon {
+    /// Create a new `BoxedPolygon` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = Vec2>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    pub fn count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Get the signed area of the polygon, positive when the vertices are counterclockwise
+    pub fn signed_area(&self) -> f32 {
+        let len = self.vertices.len();
+        (0..len)
+            .map(|i| self.vertices[i].perp_dot(self.vertices[(i + 1) % len]))
+            .sum::<f32>()
+            / 2.0
+    }
+
+    /// Get the area of the polygon
+    #[inline(always)]
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Get the perimeter of the polygon
+    pub fn perimeter(&self) -> f32 {
+        let len = self.vertices.len();
+        (0..len)
+            .map(|i| self.vertices[i].distance(self.vertices[(i + 1) % len]))
+            .sum()
+    }
+
+    /// Get the [`WindingOrder`] of the polygon
+    #[doc(alias = "orientation")]
+    pub fn winding_order(&self) -> WindingOrder {
+        let area = self.signed_area();
+        if area > f32::EPSILON {
+            WindingOrder::CounterClockwise
+        } else if area < -f32::EPSILON {
+            WindingOrder::Clockwise
+        } else {
+            WindingOrder::Invalid
+        }
+    }
+
+    /// Reverse the [`WindingOrder`] of the polygon
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+    }
+
+    /// Grow (`delta > 0`) or shrink (`delta < 0`) the polygon by `delta`.
+    ///
+    /// The result is counterclockwise regardless of the input winding. Shrinking can split
+    /// the polygon into several pieces or remove it entirely, and growing can create holes,
+    /// which are returned with a clockwise winding.
+    pub fn offset(&self, delta: f32, options: OffsetOptions) -> Vec<BoxedPolygon> {
+        let mut polygon = self.clone();
+        if polygon.winding_order() == WindingOrder::Clockwise {
+            polygon.reverse();
+        }
+        super::offset_polygons(&[polygon], delta, options)
+    }
+}
@@ -1,5 +1,6 @@
 use super::Primitive2d;
 use crate::prelude::*;
+use crate::primitive::polyline::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -30,4 +31,131 @@ impl<const N: usize> Polyline2d<N> {
     pub fn count(&self) -> usize {
         N
     }
+    /// Simplify the polyline with the Ramer-Douglas-Peucker algorithm,
+    /// removing points that lie within `epsilon` of the result
+    pub fn simplify_rdp(&self, epsilon: f32) -> BoxedPolyline2d {
+        BoxedPolyline2d::from(simplify_rdp(&self.vertices, epsilon))
+    }
+
+    /// Simplify the polyline with the Visvalingam-Whyatt algorithm,
+    /// removing points whose effective area is below `min_area`
+    pub fn simplify_vw(&self, min_area: f32) -> BoxedPolyline2d {
+        BoxedPolyline2d::from(simplify_vw(&self.vertices, min_area))
+    }
+
+    /// Smooth the polyline with `iterations` rounds of Chaikin corner-cutting
+    pub fn smooth_chaikin(&self, iterations: usize, closed: bool) -> BoxedPolyline2d {
+        BoxedPolyline2d::from(smooth_chaikin(&self.vertices, iterations, closed))
+    }
+
+    /// Resample the polyline into `count` points evenly spaced along its length
+    pub fn resample_count(&self, count: usize, closed: bool) -> BoxedPolyline2d {
+        BoxedPolyline2d::from(resample_count(&self.vertices, count, closed))
+    }
+
+    /// Resample the polyline so that its points are roughly `spacing` apart along its length
+    pub fn resample(&self, spacing: f32, closed: bool) -> BoxedPolyline2d {
+        BoxedPolyline2d::from(resample_spacing(&self.vertices, spacing, closed))
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f32 {
+        polyline_length(&self.vertices)
+    }
+
+    /// Offset the polyline by `delta` to its left, or to its right when `delta` is negative
+    pub fn offset(&self, delta: f32, options: OffsetOptions) -> BoxedPolyline2d {
+        BoxedPolyline2d::from(offset_polyline(&self.vertices, delta, options))
+    }
+
+    /// Offset the polyline by `half_width` on both sides, returning its closed outline
+    pub fn offset_outline(&self, half_width: f32, options: OffsetOptions) -> Vec<BoxedPolygon> {
+        offset_polyline_outline(&self.vertices, half_width, options)
+    }
+}
+
+/// A polyline in 2D space with a number of vertices only known at runtime
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxedPolyline2d {
+    /// The vertices of the polyline
+    pub vertices: Box<[Vec2]>,
+}
+impl Primitive2d for BoxedPolyline2d {}
+
+impl FromIterator<Vec2> for BoxedPolyline2d {
+    fn from_iter<I: IntoIterator<Item = Vec2>>(iter: I) -> Self {
+        Self {
+            vertices: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl From<Vec<Vec2>> for BoxedPolyline2d {
+    fn from(vertices: Vec<Vec2>) -> Self {
+        Self {
+            vertices: vertices.into_boxed_slice(),
+        }
+    }
+}
+
+impl<const N: usize> From<Polyline2d<N>> for BoxedPolyline2d {
+    fn from(polyline: Polyline2d<N>) -> Self {
+        Self {
+            vertices: Box::new(polyline.vertices),
+        }
+    }
+}
+
+impl BoxedPolyline2d {
+    /// Create a new `BoxedPolyline2d` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = Vec2>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    pub fn count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Simplify the polyline with the Ramer-Douglas-Peucker algorithm,
+    /// removing points that lie within `epsilon` of the result
+    pub fn simplify_rdp(&self, epsilon: f32) -> Self {
+        Self::from(simplify_rdp(&self.vertices, epsilon))
+    }
+
+    /// Simplify the polyline with the Visvalingam-Whyatt algorithm,
+    /// removing points whose effective area is below `min_area`
+    pub fn simplify_vw(&self, min_area: f32) -> Self {
+        Self::from(simplify_vw(&self.vertices, min_area))
+    }
+
+    /// Smooth the polyline with `iterations` rounds of Chaikin corner-cutting
+    pub fn smooth_chaikin(&self, iterations: usize, closed: bool) -> Self {
+        Self::from(smooth_chaikin(&self.vertices, iterations, closed))
+    }
+
+    /// Resample the polyline into `count` points evenly spaced along its length
+    pub fn resample_count(&self, count: usize, closed: bool) -> Self {
+        Self::from(resample_count(&self.vertices, count, closed))
+    }
+
+    /// Resample the polyline so that its points are roughly `spacing` apart along its length
+    pub fn resample(&self, spacing: f32, closed: bool) -> Self {
+        Self::from(resample_spacing(&self.vertices, spacing, closed))
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f32 {
+        polyline_length(&self.vertices)
+    }
+
+    /// Offset the polyline by `delta` to its left, or to its right when `delta` is negative
+    pub fn offset(&self, delta: f32, options: OffsetOptions) -> Self {
+        Self::from(offset_polyline(&self.vertices, delta, options))
+    }
+
+    /// Offset the polyline by `half_width` on both sides, returning its closed outline
+    pub fn offset_outline(&self, half_width: f32, options: OffsetOptions) -> Vec<BoxedPolygon> {
+        offset_polyline_outline(&self.vertices, half_width, options)
+    }
 }
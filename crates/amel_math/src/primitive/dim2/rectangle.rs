@@ -79,10 +79,9 @@ impl Rectangle {
     ///
     /// If the point is outside the rectangle, the returned point will be on the perimeter of the rectangle.
     /// Otherwise, it will be inside the rectangle and returned as is.
+    #[deprecated(note = "use `PointQuery2d::local_closest_point` or `PointQuery2d::closest_point`")]
     #[inline(always)]
     pub fn closest_point(&self, point: Vec2) -> Vec2 {
-        // Clamp point coordinates to the rectangle
-        let half_size = self.size / 2.0;
-        point.clamp(-half_size, half_size)
+        PointQuery2d::local_closest_point(self, point)
     }
 }
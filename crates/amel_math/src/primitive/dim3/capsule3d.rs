@@ -0,0 +1,39 @@
+use super::Primitive3d;
+
+/// A 3D capsule primitive, a cylinder along the Y axis capped with two hemispheres
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capsule3d {
+    /// The radius of the capsule
+    pub radius: f32,
+    /// Half the height of the cylindrical part, between the centers of the hemispheres
+    pub half_length: f32,
+}
+impl Primitive3d for Capsule3d {}
+
+impl Default for Capsule3d {
+    /// Returns the default [`Capsule3d`] with a radius of `0.5` and a cylinder length of `1.0`.
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            half_length: 0.5,
+        }
+    }
+}
+
+impl Capsule3d {
+    /// Create a new `Capsule3d` from a radius and the length of its cylindrical part
+    #[inline(always)]
+    pub fn new(radius: f32, length: f32) -> Self {
+        Self {
+            radius,
+            half_length: length / 2.0,
+        }
+    }
+
+    /// Get the full height of the capsule, including the hemispheres
+    #[inline(always)]
+    pub fn height(&self) -> f32 {
+        2.0 * (self.half_length + self.radius)
+    }
+}
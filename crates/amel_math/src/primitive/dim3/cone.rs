@@ -0,0 +1,59 @@
+use super::Primitive3d;
+use crate::prelude::*;
+
+/// A cone primitive with its base centered below the origin and its tip above it, along the Y axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cone {
+    /// The radius of the base
+    pub radius: f32,
+    /// The height from the base to the tip
+    pub height: f32,
+}
+impl Primitive3d for Cone {}
+
+impl Default for Cone {
+    /// Returns the default [`Cone`] with a base radius of `0.5` and a height of `1.0`.
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            height: 1.0,
+        }
+    }
+}
+
+impl Cone {
+    /// Create a new `Cone` from a base radius and full height
+    #[inline(always)]
+    pub const fn new(radius: f32, height: f32) -> Self {
+        Self { radius, height }
+    }
+
+    /// Get the base of the cone as a [`Circle`]
+    #[inline(always)]
+    pub fn base(&self) -> Circle {
+        Circle {
+            radius: self.radius,
+        }
+    }
+
+    /// Get the slant height of the cone, the distance from the edge of the base to the tip
+    #[inline(always)]
+    pub fn slant_height(&self) -> f32 {
+        self.radius.hypot(self.height)
+    }
+
+    /// Get the surface area of the side of the cone,
+    /// also known as the lateral area
+    #[inline(always)]
+    #[doc(alias = "side_area")]
+    pub fn lateral_area(&self) -> f32 {
+        PI * self.radius * self.slant_height()
+    }
+
+    /// Get the surface area of the base of the cone
+    #[inline(always)]
+    pub fn base_area(&self) -> f32 {
+        PI * self.radius.powi(2)
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Cuboid, Primitive3d, Triangle3d};
+use crate::prelude::*;
+
+/// The convex hull of a set of points in 3D space.
+///
+/// The points don't need to lie on the hull; interior points are simply never used.
+/// Queries treat a hull without points as the single point at its local origin.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvexHull3d {
+    /// The points spanning the hull
+    pub points: Box<[Vec3]>,
+}
+impl Primitive3d for ConvexHull3d {}
+
+impl FromIterator<Vec3> for ConvexHull3d {
+    fn from_iter<I: IntoIterator<Item = Vec3>>(iter: I) -> Self {
+        Self {
+            points: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl From<Vec<Vec3>> for ConvexHull3d {
+    fn from(points: Vec<Vec3>) -> Self {
+        Self {
+            points: points.into_boxed_slice(),
+        }
+    }
+}
+
+impl From<Triangle3d> for ConvexHull3d {
+    fn from(triangle: Triangle3d) -> Self {
+        Self::new(triangle.vertices)
+    }
+}
+
+impl From<Cuboid> for ConvexHull3d {
+    fn from(cuboid: Cuboid) -> Self {
+        Self::new((0..8).map(|i| {
+            let sign = Vec3::new(
+                if i & 1 == 0 { -1.0 } else { 1.0 },
+                if i & 2 == 0 { -1.0 } else { 1.0 },
+                if i & 4 == 0 { -1.0 } else { 1.0 },
+            );
+            cuboid.half_size * sign
+        }))
+    }
+}
+
+impl ConvexHull3d {
+    /// Create a new `ConvexHull3d` from the points spanning it
+    pub fn new(points: impl IntoIterator<Item = Vec3>) -> Self {
+        Self::from_iter(points)
+    }
+
+    pub fn count(&self) -> usize {
+        self.points.len()
+    }
+}
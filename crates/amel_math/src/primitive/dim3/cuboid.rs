@@ -61,10 +61,10 @@ impl Cuboid {
     ///
     /// If the point is outside the cuboid, the returned point will be on the surface of the cuboid.
     /// Otherwise, it will be inside the cuboid and returned as is.
+    #[deprecated(note = "use `PointQuery3d::local_closest_point` or `PointQuery3d::closest_point`")]
     #[inline(always)]
     pub fn closest_point(&self, point: Vec3) -> Vec3 {
-        // Clamp point coordinates to the cuboid
-        point.clamp(-self.half_size, self.half_size)
+        PointQuery3d::local_closest_point(self, point)
     }
 }
 
@@ -1,9 +1,25 @@
+pub mod capsule3d;
+pub mod cone;
+pub mod convex_hull;
 pub mod cuboid;
 pub mod cylinder;
+pub mod plane;
+pub mod polyline3d;
+pub mod segment3d;
 pub mod sphere;
+pub mod torus;
+pub mod triangle3d;
 
+pub use capsule3d::*;
+pub use cone::*;
+pub use convex_hull::*;
 pub use cuboid::*;
 pub use cylinder::*;
+pub use plane::*;
+pub use polyline3d::*;
+pub use segment3d::*;
 pub use sphere::*;
+pub use torus::*;
+pub use triangle3d::*;
 
 pub trait Primitive3d {}
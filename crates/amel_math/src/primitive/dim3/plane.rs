@@ -0,0 +1,46 @@
+use super::Primitive3d;
+use crate::prelude::*;
+
+/// A bounded plane in 3D space, a rectangle facing along `normal`
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane3d {
+    /// The unit normal of the plane
+    pub normal: Vec3,
+    /// Half of the width and depth of the plane
+    pub half_size: Vec2,
+}
+impl Primitive3d for Plane3d {}
+
+impl Default for Plane3d {
+    /// Returns the default [`Plane3d`] facing up with a width and depth of `1.0`.
+    fn default() -> Self {
+        Self {
+            normal: Vec3::Y,
+            half_size: Vec2::splat(0.5),
+        }
+    }
+}
+
+impl Plane3d {
+    /// Create a new `Plane3d` from a normal and a full width and depth.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `normal` is zero or not finite.
+    #[inline(always)]
+    pub fn new(normal: Vec3, size: Vec2) -> Self {
+        Self {
+            normal: normal
+                .try_normalize()
+                .expect("plane normal must be non-zero"),
+            half_size: size / 2.0,
+        }
+    }
+
+    /// Get the rotation taking the Y axis to the normal of the plane
+    #[inline(always)]
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_arc(Vec3::Y, self.normal)
+    }
+}
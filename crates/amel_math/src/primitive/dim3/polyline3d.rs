@@ -0,0 +1,141 @@
+use super::Primitive3d;
+use crate::prelude::*;
+use crate::primitive::polyline::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline3d<const N: usize> {
+    /// The vertices of the polyline
+    #[cfg_attr(feature = "serialize", serde(with = "super::serde::array"))]
+    pub vertices: [Vec3; N],
+}
+impl<const N: usize> Primitive3d for Polyline3d<N> {}
+
+impl<const N: usize> FromIterator<Vec3> for Polyline3d<N> {
+    fn from_iter<I: IntoIterator<Item = Vec3>>(iter: I) -> Self {
+        let mut vertices: [Vec3; N] = [Vec3::ZERO; N];
+
+        for (index, i) in iter.into_iter().take(N).enumerate() {
+            vertices[index] = i;
+        }
+        Self { vertices }
+    }
+}
+
+impl<const N: usize> Polyline3d<N> {
+    /// Create a new `Polyline3d` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = Vec3>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    pub fn count(&self) -> usize {
+        N
+    }
+    /// Simplify the polyline with the Ramer-Douglas-Peucker algorithm,
+    /// removing points that lie within `epsilon` of the result
+    pub fn simplify_rdp(&self, epsilon: f32) -> BoxedPolyline3d {
+        BoxedPolyline3d::from(simplify_rdp(&self.vertices, epsilon))
+    }
+
+    /// Simplify the polyline with the Visvalingam-Whyatt algorithm,
+    /// removing points whose effective area is below `min_area`
+    pub fn simplify_vw(&self, min_area: f32) -> BoxedPolyline3d {
+        BoxedPolyline3d::from(simplify_vw(&self.vertices, min_area))
+    }
+
+    /// Smooth the polyline with `iterations` rounds of Chaikin corner-cutting
+    pub fn smooth_chaikin(&self, iterations: usize, closed: bool) -> BoxedPolyline3d {
+        BoxedPolyline3d::from(smooth_chaikin(&self.vertices, iterations, closed))
+    }
+
+    /// Resample the polyline into `count` points evenly spaced along its length
+    pub fn resample_count(&self, count: usize, closed: bool) -> BoxedPolyline3d {
+        BoxedPolyline3d::from(resample_count(&self.vertices, count, closed))
+    }
+
+    /// Resample the polyline so that its points are roughly `spacing` apart along its length
+    pub fn resample(&self, spacing: f32, closed: bool) -> BoxedPolyline3d {
+        BoxedPolyline3d::from(resample_spacing(&self.vertices, spacing, closed))
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f32 {
+        polyline_length(&self.vertices)
+    }
+}
+
+/// A polyline in 3D space with a number of vertices only known at runtime
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxedPolyline3d {
+    /// The vertices of the polyline
+    pub vertices: Box<[Vec3]>,
+}
+impl Primitive3d for BoxedPolyline3d {}
+
+impl FromIterator<Vec3> for BoxedPolyline3d {
+    fn from_iter<I: IntoIterator<Item = Vec3>>(iter: I) -> Self {
+        Self {
+            vertices: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl From<Vec<Vec3>> for BoxedPolyline3d {
+    fn from(vertices: Vec<Vec3>) -> Self {
+        Self {
+            vertices: vertices.into_boxed_slice(),
+        }
+    }
+}
+
+impl<const N: usize> From<Polyline3d<N>> for BoxedPolyline3d {
+    fn from(polyline: Polyline3d<N>) -> Self {
+        Self {
+            vertices: Box::new(polyline.vertices),
+        }
+    }
+}
+
+impl BoxedPolyline3d {
+    /// Create a new `BoxedPolyline3d` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = Vec3>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    pub fn count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Simplify the polyline with the Ramer-Douglas-Peucker algorithm,
+    /// removing points that lie within `epsilon` of the result
+    pub fn simplify_rdp(&self, epsilon: f32) -> Self {
+        Self::from(simplify_rdp(&self.vertices, epsilon))
+    }
+
+    /// Simplify the polyline with the Visvalingam-Whyatt algorithm,
+    /// removing points whose effective area is below `min_area`
+    pub fn simplify_vw(&self, min_area: f32) -> Self {
+        Self::from(simplify_vw(&self.vertices, min_area))
+    }
+
+    /// Smooth the polyline with `iterations` rounds of Chaikin corner-cutting
+    pub fn smooth_chaikin(&self, iterations: usize, closed: bool) -> Self {
+        Self::from(smooth_chaikin(&self.vertices, iterations, closed))
+    }
+
+    /// Resample the polyline into `count` points evenly spaced along its length
+    pub fn resample_count(&self, count: usize, closed: bool) -> Self {
+        Self::from(resample_count(&self.vertices, count, closed))
+    }
+
+    /// Resample the polyline so that its points are roughly `spacing` apart along its length
+    pub fn resample(&self, spacing: f32, closed: bool) -> Self {
+        Self::from(resample_spacing(&self.vertices, spacing, closed))
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f32 {
+        polyline_length(&self.vertices)
+    }
+}
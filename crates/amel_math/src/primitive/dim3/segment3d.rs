@@ -0,0 +1,47 @@
+use super::Primitive3d;
+use crate::prelude::*;
+
+/// A line segment in 3D space centered at the origin
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment3d {
+    /// The unit direction of the segment
+    pub direction: Vec3,
+    /// Half of the length of the segment
+    pub half_length: f32,
+}
+impl Primitive3d for Segment3d {}
+
+impl Segment3d {
+    /// Create a new `Segment3d` from a direction and full length, normalizing `direction`
+    #[inline(always)]
+    pub fn new(direction: Vec3, length: f32) -> Self {
+        Self {
+            direction: direction.normalize(),
+            half_length: length * 0.5,
+        }
+    }
+
+    /// Create a new `Segment3d` from its endpoints, returned together with its center
+    #[inline(always)]
+    pub fn from_points(a: Vec3, b: Vec3) -> (Self, Vec3) {
+        (Self::new(b - a, a.distance(b)), (a + b) / 2.0)
+    }
+
+    /// Get the start point of the segment
+    #[inline(always)]
+    pub fn a(&self) -> Vec3 {
+        -self.direction * self.half_length
+    }
+
+    /// Get the end point of the segment
+    #[inline(always)]
+    pub fn b(&self) -> Vec3 {
+        self.direction * self.half_length
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f32 {
+        2.0 * self.half_length
+    }
+}
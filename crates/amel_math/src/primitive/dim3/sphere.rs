@@ -27,19 +27,10 @@ impl Sphere {
         2.0 * self.radius
     }
 
+    #[deprecated(note = "use `PointQuery3d::local_closest_point` or `PointQuery3d::closest_point`")]
     #[inline(always)]
     pub fn closest_point(&self, point: Vec3) -> Vec3 {
-        let distance_squared = point.length_squared();
-
-        if distance_squared <= self.radius.powi(2) {
-            // The point is inside the sphere.
-            point
-        } else {
-            // The point is outside the sphere.
-            // Find the closest point on the surface of the sphere.
-            let dir_to_point = point / distance_squared.sqrt();
-            self.radius * dir_to_point
-        }
+        PointQuery3d::local_closest_point(self, point)
     }
 }
 
@@ -0,0 +1,48 @@
+use super::Primitive3d;
+
+/// A torus primitive lying in the XZ plane, often representing a ring or donut shape
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Torus {
+    /// The radius of the tube
+    #[doc(alias = "ring_radius")]
+    pub minor_radius: f32,
+    /// The distance from the center of the torus to the center of the tube
+    #[doc(alias = "radius_of_revolution")]
+    pub major_radius: f32,
+}
+impl Primitive3d for Torus {}
+
+impl Default for Torus {
+    /// Returns the default [`Torus`] with a minor radius of `0.25` and a major radius of `0.75`.
+    fn default() -> Self {
+        Self {
+            minor_radius: 0.25,
+            major_radius: 0.75,
+        }
+    }
+}
+
+impl Torus {
+    /// Create a new `Torus` from an inner and outer radius
+    #[inline(always)]
+    pub fn new(inner_radius: f32, outer_radius: f32) -> Self {
+        let minor_radius = (outer_radius - inner_radius) / 2.0;
+        Self {
+            minor_radius,
+            major_radius: outer_radius - minor_radius,
+        }
+    }
+
+    /// Get the radius of the hole in the middle of the torus
+    #[inline(always)]
+    pub fn inner_radius(&self) -> f32 {
+        self.major_radius - self.minor_radius
+    }
+
+    /// Get the distance from the center of the torus to its outer edge
+    #[inline(always)]
+    pub fn outer_radius(&self) -> f32 {
+        self.major_radius + self.minor_radius
+    }
+}
@@ -0,0 +1,546 @@
+use crate::prelude::*;
+use glam::DVec2;
+use std::f64::consts::{PI, TAU};
+
+/// A circle primitive with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DCircle {
+    pub radius: f64,
+}
+impl Primitive2d for DCircle {}
+
+impl Default for DCircle {
+    fn default() -> Self {
+        Self { radius: 0.5 }
+    }
+}
+
+impl DCircle {
+    #[inline(always)]
+    pub const fn new(radius: f64) -> Self {
+        Self { radius }
+    }
+
+    #[inline(always)]
+    pub fn diameter(&self) -> f64 {
+        self.radius * 2.0
+    }
+
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    #[inline(always)]
+    pub fn perimeter(&self) -> f64 {
+        PI * self.radius * 2.0
+    }
+
+    /// Convert to the `f32` [`Circle`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Circle {
+        Circle::new(self.radius as f32)
+    }
+}
+
+impl From<Circle> for DCircle {
+    fn from(circle: Circle) -> Self {
+        Self::new(circle.radius as f64)
+    }
+}
+
+/// An ellipse primitive with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DEllipse {
+    pub half_size: DVec2,
+}
+impl Primitive2d for DEllipse {}
+
+impl Default for DEllipse {
+    fn default() -> Self {
+        Self {
+            half_size: DVec2::new(0.5, 0.5),
+        }
+    }
+}
+
+impl DEllipse {
+    #[inline(always)]
+    pub const fn new(half_width: f64, half_height: f64) -> Self {
+        Self {
+            half_size: DVec2::new(half_width, half_height),
+        }
+    }
+
+    #[inline(always)]
+    pub fn from_size(size: DVec2) -> Self {
+        Self {
+            half_size: size / 2.0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn semi_major(self) -> f64 {
+        self.half_size.max_element()
+    }
+
+    #[inline(always)]
+    pub fn semi_minor(self) -> f64 {
+        self.half_size.min_element()
+    }
+
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        PI * self.half_size.x * self.half_size.y
+    }
+
+    /// Convert to the `f32` [`Ellipse`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Ellipse {
+        Ellipse {
+            half_size: self.half_size.as_vec2(),
+        }
+    }
+}
+
+impl From<Ellipse> for DEllipse {
+    fn from(ellipse: Ellipse) -> Self {
+        Self {
+            half_size: ellipse.half_size.as_dvec2(),
+        }
+    }
+}
+
+/// A rectangle primitive with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DRectangle {
+    pub size: DVec2,
+}
+impl Primitive2d for DRectangle {}
+
+impl Default for DRectangle {
+    fn default() -> Self {
+        Self {
+            size: DVec2::splat(1.0),
+        }
+    }
+}
+
+impl DRectangle {
+    /// Create a new `DRectangle` from a full width and height
+    #[inline(always)]
+    pub fn new(width: f64, height: f64) -> Self {
+        Self::from_size(DVec2::new(width, height))
+    }
+
+    /// Create a new `DRectangle` from a given full size
+    #[inline(always)]
+    pub fn from_size(size: DVec2) -> Self {
+        Self { size }
+    }
+
+    /// Create a new `DRectangle` from two corner points
+    #[inline(always)]
+    pub fn from_corners(point1: DVec2, point2: DVec2) -> Self {
+        Self {
+            size: (point2 - point1).abs(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn width(&self) -> f64 {
+        self.size.x
+    }
+
+    #[inline(always)]
+    pub fn height(&self) -> f64 {
+        self.size.y
+    }
+
+    /// Get the area of the rectangle
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        self.size.x * self.size.y
+    }
+
+    /// Get the perimeter of the rectangle
+    #[inline(always)]
+    pub fn perimeter(&self) -> f64 {
+        2.0 * (self.size.x + self.size.y)
+    }
+
+    /// Finds the point on the rectangle that is closest to the given `point`.
+    ///
+    /// If the point is outside the rectangle, the returned point will be on the perimeter of the rectangle.
+    /// Otherwise, it will be inside the rectangle and returned as is.
+    #[inline(always)]
+    pub fn closest_point(&self, point: DVec2) -> DVec2 {
+        let half_size = self.size / 2.0;
+        point.clamp(-half_size, half_size)
+    }
+
+    /// Convert to the `f32` [`Rectangle`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Rectangle {
+        Rectangle::from_size(self.size.as_vec2())
+    }
+}
+
+impl From<Rectangle> for DRectangle {
+    fn from(rectangle: Rectangle) -> Self {
+        Self::from_size(rectangle.size.as_dvec2())
+    }
+}
+
+/// A regular polygon primitive with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DRegularPolygon {
+    pub circumcircle: DCircle,
+    pub sides: usize,
+}
+impl Primitive2d for DRegularPolygon {}
+
+impl Default for DRegularPolygon {
+    fn default() -> Self {
+        Self {
+            circumcircle: DCircle::default(),
+            sides: 6,
+        }
+    }
+}
+
+impl DRegularPolygon {
+    #[inline(always)]
+    pub fn new(circumradius: f64, sides: usize) -> Self {
+        assert!(circumradius > 0.0, "polygon has a non-positive radius");
+        assert!(sides > 2, "polygon has less than 3 sides");
+        Self {
+            circumcircle: DCircle::new(circumradius),
+            sides,
+        }
+    }
+
+    #[inline(always)]
+    pub fn circumradius(&self) -> f64 {
+        self.circumcircle.radius
+    }
+
+    #[inline(always)]
+    pub fn inradius(&self) -> f64 {
+        self.circumradius() * (PI / self.sides as f64).cos()
+    }
+
+    #[inline(always)]
+    pub fn side_length(&self) -> f64 {
+        2.0 * self.circumradius() * (PI / self.sides as f64).sin()
+    }
+
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        let angle = TAU / self.sides as f64;
+        self.sides as f64 * self.circumradius().powi(2) * angle.sin() / 2.0
+    }
+
+    #[inline(always)]
+    pub fn perimeter(&self) -> f64 {
+        self.sides as f64 * self.side_length()
+    }
+
+    pub fn vertices(self, rotation: f64) -> impl IntoIterator<Item = DVec2> {
+        // Add pi/2 so that the polygon has a vertex at the top (sin is 1.0 and cos is 0.0)
+        let start_angle = rotation + std::f64::consts::FRAC_PI_2;
+        let step = TAU / self.sides as f64;
+
+        (0..self.sides).map(move |i| {
+            let theta = start_angle + i as f64 * step;
+            let (sin, cos) = theta.sin_cos();
+            DVec2::new(cos, sin) * self.circumcircle.radius
+        })
+    }
+
+    /// Convert to the `f32` [`RegularPolygon`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> RegularPolygon {
+        RegularPolygon {
+            circumcircle: self.circumcircle.as_f32(),
+            sides: self.sides,
+        }
+    }
+}
+
+impl From<RegularPolygon> for DRegularPolygon {
+    fn from(polygon: RegularPolygon) -> Self {
+        Self {
+            circumcircle: polygon.circumcircle.into(),
+            sides: polygon.sides,
+        }
+    }
+}
+
+/// A segment in 2D space with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DSegment2d {
+    pub direction: DVec2,
+    pub half_length: f64,
+}
+impl Primitive2d for DSegment2d {}
+
+impl DSegment2d {
+    #[inline(always)]
+    pub fn new(direction: DVec2, length: f64) -> Self {
+        Self {
+            direction,
+            half_length: length * 0.5,
+        }
+    }
+
+    #[inline(always)]
+    pub fn a(&self) -> DVec2 {
+        -self.direction * self.half_length
+    }
+
+    #[inline(always)]
+    pub fn b(&self) -> DVec2 {
+        self.direction * self.half_length
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f64 {
+        2.0 * self.half_length
+    }
+
+    /// Convert to the `f32` [`Segment2d`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Segment2d {
+        Segment2d {
+            direction: self.direction.as_vec2(),
+            half_length: self.half_length as f32,
+        }
+    }
+}
+
+impl From<Segment2d> for DSegment2d {
+    fn from(segment: Segment2d) -> Self {
+        Self {
+            direction: segment.direction.as_dvec2(),
+            half_length: segment.half_length as f64,
+        }
+    }
+}
+
+/// A triangle in 2D space with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DTriangle2d {
+    /// The vertices of the triangle
+    pub vertices: [DVec2; 3],
+}
+impl Primitive2d for DTriangle2d {}
+
+impl DTriangle2d {
+    /// Create a new `DTriangle2d` from points `a`, `b`, and `c`
+    #[inline(always)]
+    pub const fn new(a: DVec2, b: DVec2, c: DVec2) -> Self {
+        Self {
+            vertices: [a, b, c],
+        }
+    }
+
+    /// Get the area of the triangle
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        let [a, b, c] = self.vertices;
+        (b - a).perp_dot(c - a).abs() / 2.0
+    }
+
+    /// Get the perimeter of the triangle
+    #[inline(always)]
+    pub fn perimeter(&self) -> f64 {
+        let [a, b, c] = self.vertices;
+        a.distance(b) + b.distance(c) + c.distance(a)
+    }
+
+    /// Get the [`WindingOrder`] of the triangle
+    #[inline(always)]
+    #[doc(alias = "orientation")]
+    pub fn winding_order(&self) -> WindingOrder {
+        let [a, b, c] = self.vertices;
+        let area = (b - a).perp_dot(c - a);
+        if area > f64::EPSILON {
+            WindingOrder::CounterClockwise
+        } else if area < -f64::EPSILON {
+            WindingOrder::Clockwise
+        } else {
+            WindingOrder::Invalid
+        }
+    }
+
+    /// Convert to the `f32` [`Triangle2d`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Triangle2d {
+        Triangle2d {
+            vertices: self.vertices.map(|v| v.as_vec2()),
+        }
+    }
+}
+
+impl From<Triangle2d> for DTriangle2d {
+    fn from(triangle: Triangle2d) -> Self {
+        Self {
+            vertices: triangle.vertices.map(|v| v.as_dvec2()),
+        }
+    }
+}
+
+/// A polyline in 2D space with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DPolyline2d<const N: usize> {
+    /// The vertices of the polyline
+    pub vertices: [DVec2; N],
+}
+impl<const N: usize> Primitive2d for DPolyline2d<N> {}
+
+impl<const N: usize> FromIterator<DVec2> for DPolyline2d<N> {
+    fn from_iter<I: IntoIterator<Item = DVec2>>(iter: I) -> Self {
+        let mut vertices = [DVec2::ZERO; N];
+        for (index, i) in iter.into_iter().take(N).enumerate() {
+            vertices[index] = i;
+        }
+        Self { vertices }
+    }
+}
+
+impl<const N: usize> DPolyline2d<N> {
+    /// Create a new `DPolyline2d` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = DVec2>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f64 {
+        self.vertices.windows(2).map(|w| w[0].distance(w[1])).sum()
+    }
+
+    /// Convert to the `f32` [`Polyline2d`], losing precision
+    pub fn as_f32(&self) -> Polyline2d<N> {
+        Polyline2d {
+            vertices: self.vertices.map(|v| v.as_vec2()),
+        }
+    }
+}
+
+impl<const N: usize> From<Polyline2d<N>> for DPolyline2d<N> {
+    fn from(polyline: Polyline2d<N>) -> Self {
+        Self {
+            vertices: polyline.vertices.map(|v| v.as_dvec2()),
+        }
+    }
+}
+
+/// A polyline in 2D space with `f64` precision and a number of vertices only known at runtime
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DBoxedPolyline2d {
+    /// The vertices of the polyline
+    pub vertices: Box<[DVec2]>,
+}
+impl Primitive2d for DBoxedPolyline2d {}
+
+impl FromIterator<DVec2> for DBoxedPolyline2d {
+    fn from_iter<I: IntoIterator<Item = DVec2>>(iter: I) -> Self {
+        Self {
+            vertices: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl DBoxedPolyline2d {
+    /// Create a new `DBoxedPolyline2d` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = DVec2>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f64 {
+        self.vertices.windows(2).map(|w| w[0].distance(w[1])).sum()
+    }
+
+    /// Convert to the `f32` [`BoxedPolyline2d`], losing precision
+    pub fn as_f32(&self) -> BoxedPolyline2d {
+        BoxedPolyline2d::new(self.vertices.iter().map(|v| v.as_vec2()))
+    }
+
+    /// Convert to the `f32` [`BoxedPolyline2d`] relative to `origin`.
+    ///
+    /// Subtracting the origin in `f64` before converting keeps the precision of
+    /// large coordinates that are close to each other.
+    pub fn as_f32_relative_to(&self, origin: DVec2) -> BoxedPolyline2d {
+        BoxedPolyline2d::new(self.vertices.iter().map(|v| (*v - origin).as_vec2()))
+    }
+}
+
+impl From<BoxedPolyline2d> for DBoxedPolyline2d {
+    fn from(polyline: BoxedPolyline2d) -> Self {
+        Self::new(polyline.vertices.iter().map(|v| v.as_dvec2()))
+    }
+}
+
+/// A closed polygon in 2D space with `f64` precision and a number of vertices only known at runtime
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DBoxedPolygon {
+    /// The vertices of the polygon
+    pub vertices: Box<[DVec2]>,
+}
+impl Primitive2d for DBoxedPolygon {}
+
+impl FromIterator<DVec2> for DBoxedPolygon {
+    fn from_iter<I: IntoIterator<Item = DVec2>>(iter: I) -> Self {
+        Self {
+            vertices: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl DBoxedPolygon {
+    /// Create a new `DBoxedPolygon` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = DVec2>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    /// Get the signed area of the polygon, positive when the vertices are counterclockwise
+    pub fn signed_area(&self) -> f64 {
+        let len = self.vertices.len();
+        (0..len)
+            .map(|i| self.vertices[i].perp_dot(self.vertices[(i + 1) % len]))
+            .sum::<f64>()
+            / 2.0
+    }
+
+    /// Get the area of the polygon
+    #[inline(always)]
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// Convert to the `f32` [`BoxedPolygon`], losing precision
+    pub fn as_f32(&self) -> BoxedPolygon {
+        BoxedPolygon::new(self.vertices.iter().map(|v| v.as_vec2()))
+    }
+
+    /// Convert to the `f32` [`BoxedPolygon`] relative to `origin`.
+    ///
+    /// Subtracting the origin in `f64` before converting keeps the precision of
+    /// large coordinates that are close to each other.
+    pub fn as_f32_relative_to(&self, origin: DVec2) -> BoxedPolygon {
+        BoxedPolygon::new(self.vertices.iter().map(|v| (*v - origin).as_vec2()))
+    }
+}
+
+impl From<BoxedPolygon> for DBoxedPolygon {
+    fn from(polygon: BoxedPolygon) -> Self {
+        Self::new(polygon.vertices.iter().map(|v| v.as_dvec2()))
+    }
+}
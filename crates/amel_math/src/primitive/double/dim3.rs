@@ -0,0 +1,287 @@
+use crate::prelude::*;
+use glam::DVec3;
+use std::f64::consts::PI;
+
+/// A sphere primitive with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DSphere {
+    /// The radius of the sphere
+    pub radius: f64,
+}
+impl Primitive3d for DSphere {}
+
+impl Default for DSphere {
+    fn default() -> Self {
+        Self { radius: 0.5 }
+    }
+}
+
+impl DSphere {
+    #[inline(always)]
+    pub const fn new(radius: f64) -> Self {
+        Self { radius }
+    }
+
+    #[inline(always)]
+    pub fn diameter(&self) -> f64 {
+        2.0 * self.radius
+    }
+
+    #[inline(always)]
+    pub fn closest_point(&self, point: DVec3) -> DVec3 {
+        let distance_squared = point.length_squared();
+
+        if distance_squared <= self.radius.powi(2) {
+            // The point is inside the sphere.
+            point
+        } else {
+            // The point is outside the sphere.
+            // Find the closest point on the surface of the sphere.
+            let dir_to_point = point / distance_squared.sqrt();
+            self.radius * dir_to_point
+        }
+    }
+
+    /// Convert to the `f32` [`Sphere`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Sphere {
+        Sphere::new(self.radius as f32)
+    }
+}
+
+impl From<Sphere> for DSphere {
+    fn from(sphere: Sphere) -> Self {
+        Self::new(sphere.radius as f64)
+    }
+}
+
+/// A cuboid primitive with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DCuboid {
+    /// Half of the width, height and depth of the cuboid
+    pub half_size: DVec3,
+}
+impl Primitive3d for DCuboid {}
+
+impl Default for DCuboid {
+    /// Returns the default [`DCuboid`] with a width, height, and depth of `1.0`.
+    fn default() -> Self {
+        Self {
+            half_size: DVec3::splat(0.5),
+        }
+    }
+}
+
+impl DCuboid {
+    /// Create a new `DCuboid` from a full x, y, and z length
+    #[inline(always)]
+    pub fn new(x_length: f64, y_length: f64, z_length: f64) -> Self {
+        Self::from_size(DVec3::new(x_length, y_length, z_length))
+    }
+
+    /// Create a new `DCuboid` from a given full size
+    #[inline(always)]
+    pub fn from_size(size: DVec3) -> Self {
+        Self {
+            half_size: size / 2.0,
+        }
+    }
+
+    /// Create a new `DCuboid` from two corner points
+    #[inline(always)]
+    pub fn from_corners(point1: DVec3, point2: DVec3) -> Self {
+        Self {
+            half_size: (point2 - point1).abs() / 2.0,
+        }
+    }
+
+    /// Get the size of the cuboid
+    #[inline(always)]
+    pub fn size(&self) -> DVec3 {
+        2.0 * self.half_size
+    }
+
+    /// Finds the point on the cuboid that is closest to the given `point`.
+    ///
+    /// If the point is outside the cuboid, the returned point will be on the surface of the cuboid.
+    /// Otherwise, it will be inside the cuboid and returned as is.
+    #[inline(always)]
+    pub fn closest_point(&self, point: DVec3) -> DVec3 {
+        point.clamp(-self.half_size, self.half_size)
+    }
+
+    /// Convert to the `f32` [`Cuboid`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Cuboid {
+        Cuboid {
+            half_size: self.half_size.as_vec3(),
+        }
+    }
+}
+
+impl From<Cuboid> for DCuboid {
+    fn from(cuboid: Cuboid) -> Self {
+        Self {
+            half_size: cuboid.half_size.as_dvec3(),
+        }
+    }
+}
+
+/// A cylinder primitive with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DCylinder {
+    /// The radius of the cylinder
+    pub radius: f64,
+    /// The half height of the cylinder
+    pub half_height: f64,
+}
+impl Primitive3d for DCylinder {}
+
+impl Default for DCylinder {
+    /// Returns the default [`DCylinder`] with a radius of `0.5` and a height of `1.0`.
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            half_height: 0.5,
+        }
+    }
+}
+
+impl DCylinder {
+    /// Create a new `DCylinder` from a radius and full height
+    #[inline(always)]
+    pub fn new(radius: f64, height: f64) -> Self {
+        Self {
+            radius,
+            half_height: height / 2.0,
+        }
+    }
+
+    /// Get the surface area of the side of the cylinder,
+    /// also known as the lateral area
+    #[inline(always)]
+    #[doc(alias = "side_area")]
+    pub fn lateral_area(&self) -> f64 {
+        4.0 * PI * self.radius * self.half_height
+    }
+
+    /// Get the surface area of one base of the cylinder
+    #[inline(always)]
+    pub fn base_area(&self) -> f64 {
+        PI * self.radius.powi(2)
+    }
+
+    /// Convert to the `f32` [`Cylinder`], losing precision
+    #[inline(always)]
+    pub fn as_f32(&self) -> Cylinder {
+        Cylinder {
+            radius: self.radius as f32,
+            half_height: self.half_height as f32,
+        }
+    }
+}
+
+impl From<Cylinder> for DCylinder {
+    fn from(cylinder: Cylinder) -> Self {
+        Self {
+            radius: cylinder.radius as f64,
+            half_height: cylinder.half_height as f64,
+        }
+    }
+}
+
+/// A polyline in 3D space with `f64` precision
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DPolyline3d<const N: usize> {
+    /// The vertices of the polyline
+    pub vertices: [DVec3; N],
+}
+impl<const N: usize> Primitive3d for DPolyline3d<N> {}
+
+impl<const N: usize> FromIterator<DVec3> for DPolyline3d<N> {
+    fn from_iter<I: IntoIterator<Item = DVec3>>(iter: I) -> Self {
+        let mut vertices = [DVec3::ZERO; N];
+        for (index, i) in iter.into_iter().take(N).enumerate() {
+            vertices[index] = i;
+        }
+        Self { vertices }
+    }
+}
+
+impl<const N: usize> DPolyline3d<N> {
+    /// Create a new `DPolyline3d` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = DVec3>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f64 {
+        self.vertices.windows(2).map(|w| w[0].distance(w[1])).sum()
+    }
+
+    /// Convert to the `f32` [`Polyline3d`], losing precision
+    pub fn as_f32(&self) -> Polyline3d<N> {
+        Polyline3d {
+            vertices: self.vertices.map(|v| v.as_vec3()),
+        }
+    }
+}
+
+impl<const N: usize> From<Polyline3d<N>> for DPolyline3d<N> {
+    fn from(polyline: Polyline3d<N>) -> Self {
+        Self {
+            vertices: polyline.vertices.map(|v| v.as_dvec3()),
+        }
+    }
+}
+
+/// A polyline in 3D space with `f64` precision and a number of vertices only known at runtime
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DBoxedPolyline3d {
+    /// The vertices of the polyline
+    pub vertices: Box<[DVec3]>,
+}
+impl Primitive3d for DBoxedPolyline3d {}
+
+impl FromIterator<DVec3> for DBoxedPolyline3d {
+    fn from_iter<I: IntoIterator<Item = DVec3>>(iter: I) -> Self {
+        Self {
+            vertices: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl DBoxedPolyline3d {
+    /// Create a new `DBoxedPolyline3d` from its vertices
+    pub fn new(vertices: impl IntoIterator<Item = DVec3>) -> Self {
+        Self::from_iter(vertices)
+    }
+
+    /// Get the total length of the polyline
+    pub fn length(&self) -> f64 {
+        self.vertices.windows(2).map(|w| w[0].distance(w[1])).sum()
+    }
+
+    /// Convert to the `f32` [`BoxedPolyline3d`], losing precision
+    pub fn as_f32(&self) -> BoxedPolyline3d {
+        BoxedPolyline3d::new(self.vertices.iter().map(|v| v.as_vec3()))
+    }
+
+    /// Convert to the `f32` [`BoxedPolyline3d`] relative to `origin`.
+    ///
+    /// Subtracting the origin in `f64` before converting keeps the precision of
+    /// large coordinates that are close to each other.
+    pub fn as_f32_relative_to(&self, origin: DVec3) -> BoxedPolyline3d {
+        BoxedPolyline3d::new(self.vertices.iter().map(|v| (*v - origin).as_vec3()))
+    }
+}
+
+impl From<BoxedPolyline3d> for DBoxedPolyline3d {
+    fn from(polyline: BoxedPolyline3d) -> Self {
+        Self::new(polyline.vertices.iter().map(|v| v.as_dvec3()))
+    }
+}
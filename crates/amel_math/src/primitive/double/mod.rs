@@ -0,0 +1,10 @@
+//! Double-precision twins of the primitives, for geometry at scales where `f32` loses precision.
+//!
+//! Every type converts losslessly from its `f32` counterpart with `From`, and back with a lossy
+//! `as_f32` for rendering.
+
+pub mod dim2;
+pub mod dim3;
+
+pub use dim2::*;
+pub use dim3::*;
@@ -1,7 +1,10 @@
 pub mod dim2;
 pub mod dim3;
+pub mod double;
+pub mod polyline;
 
 pub mod prelude {
     pub use super::dim2::*;
     pub use super::dim3::*;
+    pub use super::double::*;
 }
@@ -0,0 +1,314 @@
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::{Add, Mul, Sub};
+
+/// A point type that the polyline algorithms can operate on.
+pub trait PolylinePoint:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+    fn dot(self, rhs: Self) -> f32;
+
+    fn distance(self, rhs: Self) -> f32 {
+        let d = self - rhs;
+        d.dot(d).sqrt()
+    }
+
+    /// Get the area of the triangle `a`, `b`, `c`
+    fn triangle_area(a: Self, b: Self, c: Self) -> f32;
+}
+
+impl PolylinePoint for Vec2 {
+    #[inline(always)]
+    fn dot(self, rhs: Self) -> f32 {
+        Vec2::dot(self, rhs)
+    }
+
+    #[inline(always)]
+    fn triangle_area(a: Self, b: Self, c: Self) -> f32 {
+        (b - a).perp_dot(c - a).abs() / 2.0
+    }
+}
+
+impl PolylinePoint for Vec3 {
+    #[inline(always)]
+    fn dot(self, rhs: Self) -> f32 {
+        Vec3::dot(self, rhs)
+    }
+
+    #[inline(always)]
+    fn triangle_area(a: Self, b: Self, c: Self) -> f32 {
+        (b - a).cross(c - a).length() / 2.0
+    }
+}
+
+/// Get the point on the segment between `a` and `b` that is closest to `point`
+pub fn closest_point_on_segment<P: PolylinePoint>(point: P, a: P, b: P) -> P {
+    let ab = b - a;
+    let length_squared = ab.dot(ab);
+    if length_squared <= f32::EPSILON {
+        return a;
+    }
+    let t = ((point - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+/// Get the distance from `point` to the segment between `a` and `b`
+pub fn distance_to_segment<P: PolylinePoint>(point: P, a: P, b: P) -> f32 {
+    point.distance(closest_point_on_segment(point, a, b))
+}
+
+/// Get the point on the polyline through `points` that is closest to `point`.
+///
+/// Returns `None` if `points` is empty.
+pub fn closest_point_on_polyline<P: PolylinePoint>(
+    point: P,
+    points: &[P],
+    closed: bool,
+) -> Option<P> {
+    let len = points.len();
+    if len == 0 {
+        return None;
+    }
+    let segments = if closed && len > 2 { len } else { len - 1 };
+    (0..segments)
+        .map(|i| closest_point_on_segment(point, points[i], points[(i + 1) % len]))
+        .chain((len == 1).then_some(points[0]))
+        .min_by(|a, b| {
+            (*a - point)
+                .dot(*a - point)
+                .total_cmp(&(*b - point).dot(*b - point))
+        })
+}
+
+/// Get the total length of the polyline through `points`
+pub fn polyline_length<P: PolylinePoint>(points: &[P]) -> f32 {
+    points.windows(2).map(|w| w[0].distance(w[1])).sum()
+}
+
+/// Simplify `points` with the Ramer-Douglas-Peucker algorithm.
+///
+/// Every removed point lies within `epsilon` of the simplified polyline.
+/// The first and last points are always kept.
+pub fn simplify_rdp<P: PolylinePoint>(points: &[P], epsilon: f32) -> Vec<P> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Use an explicit stack so that long noisy inputs can't overflow the call stack
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let mut max_distance = 0.0;
+        let mut max_index = start;
+        for (i, &point) in points.iter().enumerate().take(end).skip(start + 1) {
+            let distance = distance_to_segment(point, points[start], points[end]);
+            if distance > max_distance {
+                max_distance = distance;
+                max_index = i;
+            }
+        }
+
+        if max_distance > epsilon {
+            keep[max_index] = true;
+            stack.push((start, max_index));
+            stack.push((max_index, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&point, keep)| keep.then_some(point))
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct AreaEntry {
+    area: f32,
+    index: usize,
+}
+
+impl Eq for AreaEntry {}
+
+impl PartialOrd for AreaEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AreaEntry {
+    // Reversed so that the `BinaryHeap` pops the smallest area first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+/// Simplify `points` with the Visvalingam-Whyatt algorithm.
+///
+/// Points are removed in order of the area of the triangle they form with their
+/// neighbours until every remaining triangle has an area of at least `min_area`.
+/// The first and last points are always kept.
+pub fn simplify_vw<P: PolylinePoint>(points: &[P], min_area: f32) -> Vec<P> {
+    let len = points.len();
+    if len < 3 {
+        return points.to_vec();
+    }
+
+    let mut prev: Vec<usize> = (0..len).map(|i| i.wrapping_sub(1)).collect();
+    let mut next: Vec<usize> = (1..=len).collect();
+    let mut removed = vec![false; len];
+    let mut areas = vec![f32::INFINITY; len];
+
+    let mut heap = BinaryHeap::with_capacity(len);
+    for i in 1..len - 1 {
+        areas[i] = P::triangle_area(points[i - 1], points[i], points[i + 1]);
+        heap.push(AreaEntry {
+            area: areas[i],
+            index: i,
+        });
+    }
+
+    while let Some(AreaEntry { area, index }) = heap.pop() {
+        // Skip entries that were invalidated by an earlier removal
+        if removed[index] || area != areas[index] {
+            continue;
+        }
+        if area >= min_area {
+            break;
+        }
+
+        removed[index] = true;
+        let (p, n) = (prev[index], next[index]);
+        next[p] = n;
+        prev[n] = p;
+
+        for i in [p, n] {
+            if i == 0 || i == len - 1 {
+                continue;
+            }
+            // Never let a neighbour's area fall below the area just removed,
+            // otherwise the removal order can cascade into the wrong points.
+            let area = P::triangle_area(points[prev[i]], points[i], points[next[i]]).max(area);
+            areas[i] = area;
+            heap.push(AreaEntry { area, index: i });
+        }
+    }
+
+    points
+        .iter()
+        .zip(removed)
+        .filter_map(|(&point, removed)| (!removed).then_some(point))
+        .collect()
+}
+
+/// Smooth `points` with Chaikin's corner-cutting algorithm.
+///
+/// Each iteration replaces every segment with two points at a quarter and three
+/// quarters of its length. Open polylines keep their first and last points.
+pub fn smooth_chaikin<P: PolylinePoint>(points: &[P], iterations: usize, closed: bool) -> Vec<P> {
+    let mut current = points.to_vec();
+    for _ in 0..iterations {
+        if current.len() < 3 {
+            break;
+        }
+
+        let segment_count = if closed {
+            current.len()
+        } else {
+            current.len() - 1
+        };
+        let mut smoothed = Vec::with_capacity(segment_count * 2 + 2);
+        if !closed {
+            smoothed.push(current[0]);
+        }
+        for i in 0..segment_count {
+            let a = current[i];
+            let b = current[(i + 1) % current.len()];
+            smoothed.push(a * 0.75 + b * 0.25);
+            smoothed.push(a * 0.25 + b * 0.75);
+        }
+        if !closed {
+            smoothed.push(current[current.len() - 1]);
+        }
+        current = smoothed;
+    }
+    current
+}
+
+/// Resample `points` into `count` points evenly spaced along the arc length.
+///
+/// The first and last points are kept. When `closed` is `true` the closing segment
+/// is included and the last point is not duplicated.
+pub fn resample_count<P: PolylinePoint>(points: &[P], count: usize, closed: bool) -> Vec<P> {
+    if points.len() < 2 || count < 2 {
+        return points.iter().copied().take(count).collect();
+    }
+
+    let mut path = points.to_vec();
+    if closed {
+        path.push(points[0]);
+    }
+
+    let length = polyline_length(&path);
+    if length <= f32::EPSILON {
+        return vec![points[0]; count];
+    }
+
+    let step = if closed {
+        length / count as f32
+    } else {
+        length / (count - 1) as f32
+    };
+
+    let mut resampled = Vec::with_capacity(count);
+    let mut segment = 0;
+    let mut segment_start = 0.0;
+    for i in 0..count {
+        let target = i as f32 * step;
+        while segment < path.len() - 2 {
+            let segment_length = path[segment].distance(path[segment + 1]);
+            if segment_start + segment_length >= target {
+                break;
+            }
+            segment_start += segment_length;
+            segment += 1;
+        }
+
+        let (a, b) = (path[segment], path[segment + 1]);
+        let segment_length = a.distance(b);
+        let t = if segment_length > f32::EPSILON {
+            ((target - segment_start) / segment_length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        resampled.push(a + (b - a) * t);
+    }
+
+    if !closed {
+        resampled[count - 1] = points[points.len() - 1];
+    }
+    resampled
+}
+
+/// Resample `points` so that consecutive points are `spacing` apart along the arc length.
+///
+/// The spacing is adjusted slightly so that the polyline ends exactly on its last point.
+pub fn resample_spacing<P: PolylinePoint>(points: &[P], spacing: f32, closed: bool) -> Vec<P> {
+    assert!(spacing > 0.0, "resample spacing must be positive");
+
+    let mut length = polyline_length(points);
+    if closed && points.len() > 1 {
+        length += points[points.len() - 1].distance(points[0]);
+    }
+
+    let segments = (length / spacing).round().max(1.0) as usize;
+    let count = if closed { segments } else { segments + 1 };
+    resample_count(points, count, closed)
+}
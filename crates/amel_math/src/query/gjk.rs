@@ -0,0 +1,465 @@
+//! Distance and penetration queries between convex shapes using GJK and EPA.
+//!
+//! Both algorithms work on the Minkowski difference `A - B` of the two shapes, which contains
+//! the origin exactly when the shapes overlap. GJK finds the point of the difference closest
+//! to the origin, and EPA expands GJK's final simplex to find the penetration depth when the
+//! origin is inside.
+
+use super::SupportMap3d;
+use crate::prelude::*;
+
+const MAX_ITERATIONS: usize = 64;
+const TOLERANCE: f32 = 1e-5;
+
+/// The closest points or deepest contact between two convex shapes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact3d {
+    /// The point of the first shape closest to (or deepest inside) the second one, in world space
+    pub point_a: Vec3,
+    /// The point of the second shape closest to (or deepest inside) the first one, in world space
+    pub point_b: Vec3,
+    /// The unit contact normal, pointing from the first shape towards the second one
+    pub normal: Vec3,
+    /// The distance between the shapes, negative when they overlap
+    pub distance: f32,
+}
+
+impl Contact3d {
+    /// Returns `true` if the shapes overlap
+    #[inline(always)]
+    pub fn is_penetrating(&self) -> bool {
+        self.distance < 0.0
+    }
+
+    /// Get the depth of the overlap, `0.0` when the shapes are apart
+    #[inline(always)]
+    pub fn penetration_depth(&self) -> f32 {
+        (-self.distance).max(0.0)
+    }
+}
+
+/// A vertex of the Minkowski difference, remembering the points of both shapes it came from
+#[derive(Clone, Copy, Debug)]
+struct SupportPoint {
+    point: Vec3,
+    a: Vec3,
+    b: Vec3,
+}
+
+struct MinkowskiDifference<'a, A: ?Sized, B: ?Sized> {
+    a: &'a A,
+    a_isometry: Isometry3d,
+    b: &'a B,
+    b_isometry: Isometry3d,
+}
+
+impl<A: SupportMap3d + ?Sized, B: SupportMap3d + ?Sized> MinkowskiDifference<'_, A, B> {
+    fn support(&self, direction: Vec3) -> SupportPoint {
+        let a = self.a.support_point(self.a_isometry, direction);
+        let b = self.b.support_point(self.b_isometry, -direction);
+        SupportPoint { point: a - b, a, b }
+    }
+}
+
+/// A simplex together with the barycentric weights of its point closest to the origin
+type WeightedSimplex = Vec<(SupportPoint, f32)>;
+
+fn closest_on_segment(a: SupportPoint, b: SupportPoint) -> WeightedSimplex {
+    let ab = b.point - a.point;
+    let t = -a.point.dot(ab) / ab.length_squared().max(f32::MIN_POSITIVE);
+    if t <= 0.0 {
+        vec![(a, 1.0)]
+    } else if t >= 1.0 {
+        vec![(b, 1.0)]
+    } else {
+        vec![(a, 1.0 - t), (b, t)]
+    }
+}
+
+/// Closest point of a triangle to the origin, following Ericson's Real-Time Collision Detection
+fn closest_on_triangle(a: SupportPoint, b: SupportPoint, c: SupportPoint) -> WeightedSimplex {
+    let ab = b.point - a.point;
+    let ac = c.point - a.point;
+
+    let d1 = ab.dot(-a.point);
+    let d2 = ac.dot(-a.point);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return vec![(a, 1.0)];
+    }
+
+    let d3 = ab.dot(-b.point);
+    let d4 = ac.dot(-b.point);
+    if d3 >= 0.0 && d4 <= d3 {
+        return vec![(b, 1.0)];
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return vec![(a, 1.0 - v), (b, v)];
+    }
+
+    let d5 = ab.dot(-c.point);
+    let d6 = ac.dot(-c.point);
+    if d6 >= 0.0 && d5 <= d6 {
+        return vec![(c, 1.0)];
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return vec![(a, 1.0 - w), (c, w)];
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return vec![(b, 1.0 - w), (c, w)];
+    }
+
+    let denominator = va + vb + vc;
+    if denominator.abs() <= f32::MIN_POSITIVE {
+        // Degenerate triangle, fall back to its longest edge
+        let (start, end) = [(a, b), (b, c), (c, a)]
+            .into_iter()
+            .max_by(|(a1, b1), (a2, b2)| {
+                a1.point
+                    .distance_squared(b1.point)
+                    .total_cmp(&a2.point.distance_squared(b2.point))
+            })
+            .unwrap();
+        return closest_on_segment(start, end);
+    }
+    let v = vb / denominator;
+    let w = vc / denominator;
+    vec![(a, 1.0 - v - w), (b, v), (c, w)]
+}
+
+/// Returns `true` if the origin and `d` lie on opposite sides of the plane through `a`, `b`, `c`
+fn origin_outside_of_plane(a: Vec3, b: Vec3, c: Vec3, d: Vec3) -> bool {
+    let normal = (b - a).cross(c - a);
+    let sign_origin = (-a).dot(normal);
+    let sign_d = (d - a).dot(normal);
+    sign_origin * sign_d < 0.0 || sign_d.abs() <= f32::EPSILON
+}
+
+/// Closest point of a tetrahedron to the origin, or `None` if the origin is inside it
+fn closest_on_tetrahedron(simplex: [SupportPoint; 4]) -> Option<WeightedSimplex> {
+    let [a, b, c, d] = simplex;
+    let faces = [(a, b, c, d), (a, c, d, b), (a, d, b, c), (b, d, c, a)];
+
+    let mut best: Option<(f32, WeightedSimplex)> = None;
+    for (p, q, r, opposite) in faces {
+        if !origin_outside_of_plane(p.point, q.point, r.point, opposite.point) {
+            continue;
+        }
+        let candidate = closest_on_triangle(p, q, r);
+        let distance = weighted_point(&candidate).length_squared();
+        if best.as_ref().is_none_or(|(best, _)| distance < *best) {
+            best = Some((distance, candidate));
+        }
+    }
+    best.map(|(_, simplex)| simplex)
+}
+
+fn weighted_point(simplex: &WeightedSimplex) -> Vec3 {
+    simplex.iter().map(|(p, w)| p.point * *w).sum()
+}
+
+/// The outcome of running GJK on a pair of shapes
+enum GjkResult {
+    /// The shapes are apart, with the closest points and distance
+    Separated(Contact3d),
+    /// The shapes overlap, with the final simplex enclosing the origin
+    Overlapping(Vec<SupportPoint>),
+}
+
+fn gjk<A: SupportMap3d + ?Sized, B: SupportMap3d + ?Sized>(
+    difference: &MinkowskiDifference<A, B>,
+) -> GjkResult {
+    let initial = difference.b_isometry.translation - difference.a_isometry.translation;
+    let direction = if initial.length_squared() > f32::EPSILON {
+        -initial
+    } else {
+        Vec3::X
+    };
+
+    let mut simplex: WeightedSimplex = vec![(difference.support(direction), 1.0)];
+    let mut closest = simplex[0].0.point;
+
+    for _ in 0..MAX_ITERATIONS {
+        let distance_squared = closest.length_squared();
+        if distance_squared <= TOLERANCE * TOLERANCE {
+            return GjkResult::Overlapping(simplex.into_iter().map(|(p, _)| p).collect());
+        }
+
+        let new = difference.support(-closest);
+        // Stop once the new support point can't bring the simplex meaningfully closer
+        let progress = distance_squared - closest.dot(new.point);
+        let duplicate = simplex
+            .iter()
+            .any(|(p, _)| p.point.distance_squared(new.point) <= TOLERANCE * TOLERANCE);
+        if progress <= TOLERANCE * distance_squared.max(TOLERANCE) || duplicate {
+            break;
+        }
+
+        let mut points: Vec<SupportPoint> = simplex.iter().map(|(p, _)| *p).collect();
+        points.push(new);
+        simplex = match *points.as_slice() {
+            [a, b] => closest_on_segment(a, b),
+            [a, b, c] => closest_on_triangle(a, b, c),
+            [a, b, c, d] => match closest_on_tetrahedron([a, b, c, d]) {
+                Some(simplex) => simplex,
+                None => return GjkResult::Overlapping(points),
+            },
+            _ => unreachable!("GJK simplex has more than four points"),
+        };
+
+        let next = weighted_point(&simplex);
+        if next.length_squared() >= distance_squared {
+            // Numerical trouble, the previous simplex was already as close as we can get
+            break;
+        }
+        closest = next;
+    }
+
+    let distance = closest.length();
+    if distance <= TOLERANCE {
+        return GjkResult::Overlapping(simplex.into_iter().map(|(p, _)| p).collect());
+    }
+
+    let point_a = simplex.iter().map(|(p, w)| p.a * *w).sum();
+    let point_b = simplex.iter().map(|(p, w)| p.b * *w).sum();
+    GjkResult::Separated(Contact3d {
+        point_a,
+        point_b,
+        normal: -closest / distance,
+        distance,
+    })
+}
+
+/// Grow a simplex that encloses the origin into a tetrahedron, as EPA needs a volume to start from
+fn blow_up_simplex<A: SupportMap3d + ?Sized, B: SupportMap3d + ?Sized>(
+    difference: &MinkowskiDifference<A, B>,
+    mut simplex: Vec<SupportPoint>,
+) -> Option<[SupportPoint; 4]> {
+    const AXES: [Vec3; 6] = [
+        Vec3::X,
+        Vec3::NEG_X,
+        Vec3::Y,
+        Vec3::NEG_Y,
+        Vec3::Z,
+        Vec3::NEG_Z,
+    ];
+
+    let is_new = |simplex: &[SupportPoint], candidate: &SupportPoint| {
+        simplex
+            .iter()
+            .all(|p| p.point.distance_squared(candidate.point) > TOLERANCE * TOLERANCE)
+    };
+
+    if simplex.len() == 1 {
+        let found = AXES
+            .iter()
+            .map(|&axis| difference.support(axis))
+            .find(|candidate| is_new(&simplex, candidate))?;
+        simplex.push(found);
+    }
+
+    if simplex.len() == 2 {
+        let axis = (simplex[1].point - simplex[0].point).normalize();
+        let perpendicular = axis.any_orthonormal_vector();
+        let found = (0..6)
+            .map(|i| Quat::from_axis_angle(axis, i as f32 * FRAC_PI_3) * perpendicular)
+            .map(|direction| difference.support(direction))
+            .find(|candidate| {
+                let offset = candidate.point - simplex[0].point;
+                offset.cross(axis).length_squared() > TOLERANCE * TOLERANCE
+            })?;
+        simplex.push(found);
+    }
+
+    if simplex.len() == 3 {
+        let normal = (simplex[1].point - simplex[0].point)
+            .cross(simplex[2].point - simplex[0].point)
+            .normalize_or_zero();
+        let found = [normal, -normal]
+            .into_iter()
+            .map(|direction| difference.support(direction))
+            .find(|candidate| (candidate.point - simplex[0].point).dot(normal).abs() > TOLERANCE)?;
+        simplex.push(found);
+    }
+
+    simplex.try_into().ok()
+}
+
+#[derive(Clone, Copy)]
+struct Face {
+    vertices: [usize; 3],
+    normal: Vec3,
+    distance: f32,
+}
+
+fn make_face(points: &[SupportPoint], vertices: [usize; 3], interior: Vec3) -> Face {
+    let [a, b, c] = vertices.map(|i| points[i].point);
+    let mut normal = (b - a).cross(c - a).normalize_or_zero();
+    let mut vertices = vertices;
+    if normal.dot(a - interior) < 0.0 {
+        normal = -normal;
+        vertices.swap(1, 2);
+    }
+    Face {
+        vertices,
+        normal,
+        distance: normal.dot(a),
+    }
+}
+
+/// Find the penetration of two overlapping shapes with the expanding polytope algorithm
+fn epa<A: SupportMap3d + ?Sized, B: SupportMap3d + ?Sized>(
+    difference: &MinkowskiDifference<A, B>,
+    simplex: Vec<SupportPoint>,
+) -> Contact3d {
+    let Some(tetrahedron) = blow_up_simplex(difference, simplex.clone()) else {
+        // The difference is flat, so the shapes are only touching
+        let point = simplex[0];
+        return Contact3d {
+            point_a: point.a,
+            point_b: point.b,
+            normal: (difference.b_isometry.translation - difference.a_isometry.translation)
+                .normalize_or(Vec3::Y),
+            distance: 0.0,
+        };
+    };
+
+    let mut points = tetrahedron.to_vec();
+    let interior = points.iter().map(|p| p.point).sum::<Vec3>() / 4.0;
+    let mut faces: Vec<Face> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .into_iter()
+        .map(|vertices| make_face(&points, vertices, interior))
+        .collect();
+
+    let mut closest = faces[0];
+    for _ in 0..MAX_ITERATIONS {
+        closest = *faces
+            .iter()
+            .filter(|face| face.normal != Vec3::ZERO)
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+            .unwrap_or(&faces[0]);
+
+        let new = difference.support(closest.normal);
+        if new.point.dot(closest.normal) - closest.distance
+            <= TOLERANCE.max(closest.distance * 1e-4)
+        {
+            break;
+        }
+
+        // Remove every face the new point can see and stitch the hole to the new point
+        let new_index = points.len();
+        points.push(new);
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            let visible = face.normal.dot(new.point - points[face.vertices[0]].point) > 0.0;
+            if visible {
+                for i in 0..3 {
+                    let edge = (face.vertices[i], face.vertices[(i + 1) % 3]);
+                    if let Some(shared) = horizon.iter().position(|&e| e == (edge.1, edge.0)) {
+                        horizon.swap_remove(shared);
+                    } else {
+                        horizon.push(edge);
+                    }
+                }
+            }
+            !visible
+        });
+        if horizon.is_empty() {
+            break;
+        }
+        faces.extend(
+            horizon
+                .into_iter()
+                .map(|(a, b)| make_face(&points, [a, b, new_index], interior)),
+        );
+    }
+
+    // Express the origin's projection on the closest face in barycentric coordinates
+    let [a, b, c] = closest.vertices.map(|i| points[i]);
+    let projection = closest.normal * closest.distance;
+    let weights = barycentric(projection, a.point, b.point, c.point);
+    Contact3d {
+        point_a: a.a * weights.x + b.a * weights.y + c.a * weights.z,
+        point_b: a.b * weights.x + b.b * weights.y + c.b * weights.z,
+        normal: closest.normal,
+        distance: -closest.distance,
+    }
+}
+
+fn barycentric(point: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    let (v0, v1, v2) = (b - a, c - a, point - a);
+    let (d00, d01, d11) = (v0.dot(v0), v0.dot(v1), v1.dot(v1));
+    let (d20, d21) = (v2.dot(v0), v2.dot(v1));
+    let denominator = d00 * d11 - d01 * d01;
+    if denominator.abs() <= f32::MIN_POSITIVE {
+        return Vec3::new(1.0, 0.0, 0.0);
+    }
+    let v = (d11 * d20 - d01 * d21) / denominator;
+    let w = (d00 * d21 - d01 * d20) / denominator;
+    Vec3::new(1.0 - v - w, v, w)
+}
+
+/// Returns `true` if the two convex shapes overlap
+pub fn intersects_3d<A: SupportMap3d + ?Sized, B: SupportMap3d + ?Sized>(
+    a: &A,
+    a_isometry: impl Into<Isometry3d>,
+    b: &B,
+    b_isometry: impl Into<Isometry3d>,
+) -> bool {
+    let difference = MinkowskiDifference {
+        a,
+        a_isometry: a_isometry.into(),
+        b,
+        b_isometry: b_isometry.into(),
+    };
+    matches!(gjk(&difference), GjkResult::Overlapping(_))
+}
+
+/// Get the closest points between two convex shapes with GJK, or `None` if they overlap
+pub fn closest_points_3d<A: SupportMap3d + ?Sized, B: SupportMap3d + ?Sized>(
+    a: &A,
+    a_isometry: impl Into<Isometry3d>,
+    b: &B,
+    b_isometry: impl Into<Isometry3d>,
+) -> Option<Contact3d> {
+    let difference = MinkowskiDifference {
+        a,
+        a_isometry: a_isometry.into(),
+        b,
+        b_isometry: b_isometry.into(),
+    };
+    match gjk(&difference) {
+        GjkResult::Separated(contact) => Some(contact),
+        GjkResult::Overlapping(_) => None,
+    }
+}
+
+/// Get the closest points between two convex shapes, or their deepest points and the
+/// penetration depth with EPA if they overlap.
+///
+/// Moving the second shape by `-contact.distance` along `contact.normal` separates overlapping shapes.
+pub fn contact_3d<A: SupportMap3d + ?Sized, B: SupportMap3d + ?Sized>(
+    a: &A,
+    a_isometry: impl Into<Isometry3d>,
+    b: &B,
+    b_isometry: impl Into<Isometry3d>,
+) -> Contact3d {
+    let difference = MinkowskiDifference {
+        a,
+        a_isometry: a_isometry.into(),
+        b,
+        b_isometry: b_isometry.into(),
+    };
+    match gjk(&difference) {
+        GjkResult::Separated(contact) => contact,
+        GjkResult::Overlapping(simplex) => epa(&difference, simplex),
+    }
+}
@@ -0,0 +1,9 @@
+pub mod gjk;
+pub mod query2d;
+pub mod query3d;
+pub mod support;
+
+pub use gjk::*;
+pub use query2d::*;
+pub use query3d::*;
+pub use support::*;
@@ -0,0 +1,550 @@
+use crate::prelude::*;
+use crate::primitive::polyline::{closest_point_on_polyline, closest_point_on_segment};
+
+/// Point queries against a 2D shape placed in the world by an [`Isometry2d`]
+pub trait PointQuery2d {
+    /// Returns `true` if `point`, given in the shape's local space, lies inside the shape
+    fn local_contains_point(&self, point: Vec2) -> bool;
+
+    /// Finds the point on the shape that is closest to `point`, both in the shape's local space.
+    ///
+    /// Points inside a solid shape are returned as is.
+    fn local_closest_point(&self, point: Vec2) -> Vec2;
+
+    /// Returns `true` if `point` lies inside the shape placed by `isometry`
+    #[inline(always)]
+    fn contains_point(&self, isometry: impl Into<Isometry2d>, point: Vec2) -> bool {
+        self.local_contains_point(isometry.into().inverse_transform_point(point))
+    }
+
+    /// Finds the point on the shape placed by `isometry` that is closest to `point`
+    #[inline(always)]
+    fn closest_point(&self, isometry: impl Into<Isometry2d>, point: Vec2) -> Vec2 {
+        let isometry = isometry.into();
+        isometry.transform_point(self.local_closest_point(isometry.inverse_transform_point(point)))
+    }
+
+    /// Get the distance from `point` to the shape placed by `isometry`, `0.0` inside it
+    #[inline(always)]
+    fn distance_to_point(&self, isometry: impl Into<Isometry2d>, point: Vec2) -> f32 {
+        let local = isometry.into().inverse_transform_point(point);
+        local.distance(self.local_closest_point(local))
+    }
+}
+
+/// Ray casts against a 2D shape placed in the world by an [`Isometry2d`].
+///
+/// Rays starting inside a solid shape hit it at a distance of `0.0`,
+/// with a normal pointing against the ray.
+pub trait RayCast2d {
+    /// Cast a ray given in the shape's local space, returning the hit in local space
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d>;
+
+    /// Cast `ray` against the shape placed by `isometry`
+    #[inline(always)]
+    fn cast_ray(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> Option<RayHit2d> {
+        let isometry = isometry.into();
+        let local_ray = Ray2d {
+            origin: isometry.inverse_transform_point(ray.origin),
+            direction: isometry.inverse_transform_vector(ray.direction),
+        };
+        self.local_cast_ray(local_ray, max_distance)
+            .map(|hit| RayHit2d {
+                distance: hit.distance,
+                normal: isometry.transform_vector(hit.normal),
+            })
+    }
+
+    /// Returns `true` if `ray` hits the shape placed by `isometry` within `max_distance`
+    #[inline(always)]
+    fn intersects_ray(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        ray: Ray2d,
+        max_distance: f32,
+    ) -> bool {
+        self.cast_ray(isometry, ray, max_distance).is_some()
+    }
+}
+
+/// Overlap tests between two 2D shapes, each placed in the world by its own [`Isometry2d`]
+pub trait IntersectionQuery2d<Other: ?Sized = Self> {
+    /// Returns `true` if the shape placed by `isometry` overlaps `other` placed by `other_isometry`.
+    ///
+    /// Shapes that only touch count as intersecting.
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        other: &Other,
+        other_isometry: impl Into<Isometry2d>,
+    ) -> bool;
+}
+
+/// A convex polygon described by its vertices, which can be tested against any other one
+/// with the separating axis theorem
+pub trait ConvexPolygon2d {
+    /// Get the vertices of the polygon in its local space, in order around its perimeter
+    fn local_vertices(&self) -> Vec<Vec2>;
+}
+
+/// Returns `true` unless one of the edge normals of `a` or `b` separates the two polygons
+fn convex_polygons_intersect(a: &[Vec2], b: &[Vec2]) -> bool {
+    let project = |vertices: &[Vec2], axis: Vec2| {
+        vertices
+            .iter()
+            .map(|vertex| vertex.dot(axis))
+            .fold((f32::MAX, f32::MIN), |(min, max), d| (min.min(d), max.max(d)))
+    };
+    [a, b].into_iter().all(|polygon| {
+        (0..polygon.len()).all(|i| {
+            let axis = (polygon[(i + 1) % polygon.len()] - polygon[i]).perp();
+            let (a_min, a_max) = project(a, axis);
+            let (b_min, b_max) = project(b, axis);
+            a_min <= b_max && b_min <= a_max
+        })
+    })
+}
+
+/// Returns `true` if `point` lies inside the polygon with the given vertices (even-odd rule)
+fn polygon_contains_point(vertices: &[Vec2], point: Vec2) -> bool {
+    let len = vertices.len();
+    let mut inside = false;
+    for i in 0..len {
+        let (a, b) = (vertices[i], vertices[(i + 1) % len]);
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Finds the point on the solid polygon with the given vertices that is closest to `point`.
+///
+/// A polygon without vertices is treated as its local origin.
+fn polygon_closest_point(vertices: &[Vec2], point: Vec2) -> Vec2 {
+    if polygon_contains_point(vertices, point) {
+        point
+    } else {
+        closest_point_on_polyline(point, vertices, true).unwrap_or(Vec2::ZERO)
+    }
+}
+
+/// Intersect a ray with the segment between `a` and `b`
+fn ray_segment(ray: Ray2d, a: Vec2, b: Vec2, max_distance: f32) -> Option<RayHit2d> {
+    let edge = b - a;
+    let denominator = ray.direction.perp_dot(edge);
+    if denominator.abs() <= f32::EPSILON {
+        return None;
+    }
+
+    let offset = a - ray.origin;
+    let distance = offset.perp_dot(edge) / denominator;
+    let t = offset.perp_dot(ray.direction) / denominator;
+    if !(0.0..=max_distance).contains(&distance) || !(0.0..=1.0).contains(&t) {
+        return None;
+    }
+
+    let normal = edge.perp().normalize();
+    Some(RayHit2d {
+        distance,
+        normal: if normal.dot(ray.direction) > 0.0 {
+            -normal
+        } else {
+            normal
+        },
+    })
+}
+
+/// Cast a ray against the polyline with the given vertices
+fn ray_polyline(
+    ray: Ray2d,
+    vertices: &[Vec2],
+    closed: bool,
+    max_distance: f32,
+) -> Option<RayHit2d> {
+    let len = vertices.len();
+    let segments = if closed && len > 2 {
+        len
+    } else {
+        len.saturating_sub(1)
+    };
+    (0..segments)
+        .filter_map(|i| ray_segment(ray, vertices[i], vertices[(i + 1) % len], max_distance))
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+/// Cast a ray against the solid polygon with the given vertices
+fn ray_polygon(ray: Ray2d, vertices: &[Vec2], max_distance: f32) -> Option<RayHit2d> {
+    if polygon_contains_point(vertices, ray.origin) {
+        return Some(RayHit2d {
+            distance: 0.0,
+            normal: -ray.direction,
+        });
+    }
+    ray_polyline(ray, vertices, true, max_distance)
+}
+
+/// Intersect a ray with the unit circle, returning the entry distance in units of `direction`
+fn ray_unit_circle(origin: Vec2, direction: Vec2) -> Option<f32> {
+    let a = direction.length_squared();
+    let b = origin.dot(direction);
+    let c = origin.length_squared() - 1.0;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let discriminant = b * b - a * c;
+    if b > 0.0 || discriminant < 0.0 {
+        return None;
+    }
+    Some((-b - discriminant.sqrt()) / a)
+}
+
+impl PointQuery2d for Circle {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        point.length_squared() <= self.radius * self.radius
+    }
+
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        point.clamp_length_max(self.radius)
+    }
+}
+
+impl RayCast2d for Circle {
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        let distance = ray_unit_circle(ray.origin / self.radius, ray.direction / self.radius)?;
+        if distance > max_distance {
+            return None;
+        }
+        let normal = if distance == 0.0 {
+            -ray.direction
+        } else {
+            ray.get_point(distance).normalize()
+        };
+        Some(RayHit2d { distance, normal })
+    }
+}
+
+impl PointQuery2d for Ellipse {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        (point / self.half_size).length_squared() <= 1.0
+    }
+
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        if self.local_contains_point(point) {
+            return point;
+        }
+
+        // Iterate on the quarter arc by approximating the ellipse locally with a circle
+        // around its center of curvature, which converges in a handful of steps
+        let (a, b) = (self.half_size.x, self.half_size.y);
+        let p = point.abs();
+        let mut t = Vec2::splat(FRAC_1_SQRT_2);
+        for _ in 0..4 {
+            let on_ellipse = Vec2::new(a * t.x, b * t.y);
+            let evolute = Vec2::new(
+                (a * a - b * b) * t.x.powi(3) / a,
+                (b * b - a * a) * t.y.powi(3) / b,
+            );
+            let r = on_ellipse - evolute;
+            let q = p - evolute;
+            let q_length = q.length();
+            if q_length <= f32::EPSILON {
+                break;
+            }
+            t = ((q * r.length() / q_length + evolute) / self.half_size)
+                .clamp(Vec2::ZERO, Vec2::ONE)
+                .normalize_or(t);
+        }
+        (self.half_size * t).copysign(point)
+    }
+}
+
+impl RayCast2d for Ellipse {
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        // Scale the ellipse into a unit circle, which keeps the ray parameter intact
+        let distance =
+            ray_unit_circle(ray.origin / self.half_size, ray.direction / self.half_size)?;
+        if distance > max_distance {
+            return None;
+        }
+        let normal = if distance == 0.0 {
+            -ray.direction
+        } else {
+            (ray.get_point(distance) / (self.half_size * self.half_size)).normalize()
+        };
+        Some(RayHit2d { distance, normal })
+    }
+}
+
+impl PointQuery2d for Rectangle {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        point.abs().cmple(self.size / 2.0).all()
+    }
+
+    /// If the point is outside the rectangle, the returned point will be on its perimeter.
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        let half_size = self.size / 2.0;
+        point.clamp(-half_size, half_size)
+    }
+}
+
+impl RayCast2d for Rectangle {
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        if self.local_contains_point(ray.origin) {
+            return Some(RayHit2d {
+                distance: 0.0,
+                normal: -ray.direction,
+            });
+        }
+
+        // Slab test, the entry distance is the largest of the per-axis entry distances
+        let half_size = self.size / 2.0;
+        let inverse = ray.direction.recip();
+        let t1 = (-half_size - ray.origin) * inverse;
+        let t2 = (half_size - ray.origin) * inverse;
+        let near = t1.min(t2);
+        let far = t1.max(t2);
+        let distance = near.max_element();
+        if distance > far.min_element() || distance < 0.0 || distance > max_distance {
+            return None;
+        }
+
+        let normal = if near.x > near.y {
+            Vec2::new(-ray.direction.x.signum(), 0.0)
+        } else {
+            Vec2::new(0.0, -ray.direction.y.signum())
+        };
+        Some(RayHit2d { distance, normal })
+    }
+}
+
+impl PointQuery2d for RegularPolygon {
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        let vertices: Vec<Vec2> = self.vertices(0.0).into_iter().collect();
+        polygon_contains_point(&vertices, point)
+    }
+
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        let vertices: Vec<Vec2> = self.vertices(0.0).into_iter().collect();
+        polygon_closest_point(&vertices, point)
+    }
+}
+
+impl RayCast2d for RegularPolygon {
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        let vertices: Vec<Vec2> = self.vertices(0.0).into_iter().collect();
+        ray_polygon(ray, &vertices, max_distance)
+    }
+}
+
+impl PointQuery2d for Triangle2d {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        polygon_contains_point(&self.vertices, point)
+    }
+
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        polygon_closest_point(&self.vertices, point)
+    }
+}
+
+impl RayCast2d for Triangle2d {
+    #[inline(always)]
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        ray_polygon(ray, &self.vertices, max_distance)
+    }
+}
+
+impl PointQuery2d for BoxedPolygon {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        polygon_contains_point(&self.vertices, point)
+    }
+
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        polygon_closest_point(&self.vertices, point)
+    }
+}
+
+impl RayCast2d for BoxedPolygon {
+    #[inline(always)]
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        ray_polygon(ray, &self.vertices, max_distance)
+    }
+}
+
+impl PointQuery2d for Segment2d {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        self.local_closest_point(point).distance_squared(point) <= f32::EPSILON
+    }
+
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        closest_point_on_segment(point, self.a(), self.b())
+    }
+}
+
+impl RayCast2d for Segment2d {
+    #[inline(always)]
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        ray_segment(ray, self.a(), self.b(), max_distance)
+    }
+}
+
+impl<const N: usize> PointQuery2d for Polyline2d<N> {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        closest_point_on_polyline(point, &self.vertices, false)
+            .is_some_and(|closest| closest.distance_squared(point) <= f32::EPSILON)
+    }
+
+    /// A polyline without vertices is treated as its local origin.
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        closest_point_on_polyline(point, &self.vertices, false).unwrap_or(Vec2::ZERO)
+    }
+}
+
+impl<const N: usize> RayCast2d for Polyline2d<N> {
+    #[inline(always)]
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        ray_polyline(ray, &self.vertices, false, max_distance)
+    }
+}
+
+impl PointQuery2d for BoxedPolyline2d {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec2) -> bool {
+        closest_point_on_polyline(point, &self.vertices, false)
+            .is_some_and(|closest| closest.distance_squared(point) <= f32::EPSILON)
+    }
+
+    /// A polyline without vertices is treated as its local origin.
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec2) -> Vec2 {
+        closest_point_on_polyline(point, &self.vertices, false).unwrap_or(Vec2::ZERO)
+    }
+}
+
+impl RayCast2d for BoxedPolyline2d {
+    #[inline(always)]
+    fn local_cast_ray(&self, ray: Ray2d, max_distance: f32) -> Option<RayHit2d> {
+        ray_polyline(ray, &self.vertices, false, max_distance)
+    }
+}
+
+impl ConvexPolygon2d for Rectangle {
+    fn local_vertices(&self) -> Vec<Vec2> {
+        let half_size = self.size / 2.0;
+        vec![
+            -half_size,
+            Vec2::new(half_size.x, -half_size.y),
+            half_size,
+            Vec2::new(-half_size.x, half_size.y),
+        ]
+    }
+}
+
+impl ConvexPolygon2d for RegularPolygon {
+    #[inline(always)]
+    fn local_vertices(&self) -> Vec<Vec2> {
+        self.vertices(0.0).into_iter().collect()
+    }
+}
+
+impl ConvexPolygon2d for Triangle2d {
+    #[inline(always)]
+    fn local_vertices(&self) -> Vec<Vec2> {
+        self.vertices.to_vec()
+    }
+}
+
+impl<S: PointQuery2d> IntersectionQuery2d<S> for Circle {
+    #[inline(always)]
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        other: &S,
+        other_isometry: impl Into<Isometry2d>,
+    ) -> bool {
+        other.distance_to_point(other_isometry, isometry.into().translation) <= self.radius
+    }
+}
+
+impl<A: ConvexPolygon2d, B: ConvexPolygon2d> IntersectionQuery2d<B> for A {
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        other: &B,
+        other_isometry: impl Into<Isometry2d>,
+    ) -> bool {
+        let (isometry, other_isometry) = (isometry.into(), other_isometry.into());
+        let a: Vec<Vec2> = self
+            .local_vertices()
+            .into_iter()
+            .map(|vertex| isometry.transform_point(vertex))
+            .collect();
+        let b: Vec<Vec2> = other
+            .local_vertices()
+            .into_iter()
+            .map(|vertex| other_isometry.transform_point(vertex))
+            .collect();
+        convex_polygons_intersect(&a, &b)
+    }
+}
+
+macro_rules! impl_intersects_circle {
+    ($($shape:ty),*) => {
+        $(
+            impl IntersectionQuery2d<Circle> for $shape {
+                #[inline(always)]
+                fn intersects(
+                    &self,
+                    isometry: impl Into<Isometry2d>,
+                    other: &Circle,
+                    other_isometry: impl Into<Isometry2d>,
+                ) -> bool {
+                    other.intersects(other_isometry, self, isometry)
+                }
+            }
+        )*
+    };
+}
+
+impl_intersects_circle!(
+    Ellipse,
+    Rectangle,
+    RegularPolygon,
+    Triangle2d,
+    BoxedPolygon,
+    Segment2d,
+    BoxedPolyline2d
+);
+
+impl<const N: usize> IntersectionQuery2d<Circle> for Polyline2d<N> {
+    #[inline(always)]
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry2d>,
+        other: &Circle,
+        other_isometry: impl Into<Isometry2d>,
+    ) -> bool {
+        other.intersects(other_isometry, self, isometry)
+    }
+}
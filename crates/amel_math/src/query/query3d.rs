@@ -0,0 +1,341 @@
+use crate::prelude::*;
+use crate::primitive::polyline::closest_point_on_polyline;
+
+/// Point queries against a 3D shape placed in the world by an [`Isometry3d`]
+pub trait PointQuery3d {
+    /// Returns `true` if `point`, given in the shape's local space, lies inside the shape
+    fn local_contains_point(&self, point: Vec3) -> bool;
+
+    /// Finds the point on the shape that is closest to `point`, both in the shape's local space.
+    ///
+    /// Points inside a solid shape are returned as is.
+    fn local_closest_point(&self, point: Vec3) -> Vec3;
+
+    /// Returns `true` if `point` lies inside the shape placed by `isometry`
+    #[inline(always)]
+    fn contains_point(&self, isometry: impl Into<Isometry3d>, point: Vec3) -> bool {
+        self.local_contains_point(isometry.into().inverse_transform_point(point))
+    }
+
+    /// Finds the point on the shape placed by `isometry` that is closest to `point`
+    #[inline(always)]
+    fn closest_point(&self, isometry: impl Into<Isometry3d>, point: Vec3) -> Vec3 {
+        let isometry = isometry.into();
+        isometry.transform_point(self.local_closest_point(isometry.inverse_transform_point(point)))
+    }
+
+    /// Get the distance from `point` to the shape placed by `isometry`, `0.0` inside it
+    #[inline(always)]
+    fn distance_to_point(&self, isometry: impl Into<Isometry3d>, point: Vec3) -> f32 {
+        let local = isometry.into().inverse_transform_point(point);
+        local.distance(self.local_closest_point(local))
+    }
+}
+
+/// Ray casts against a 3D shape placed in the world by an [`Isometry3d`].
+///
+/// Rays starting inside a solid shape hit it at a distance of `0.0`,
+/// with a normal pointing against the ray.
+pub trait RayCast3d {
+    /// Cast a ray given in the shape's local space, returning the hit in local space
+    fn local_cast_ray(&self, ray: Ray3d, max_distance: f32) -> Option<RayHit3d>;
+
+    /// Cast `ray` against the shape placed by `isometry`
+    #[inline(always)]
+    fn cast_ray(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> Option<RayHit3d> {
+        let isometry = isometry.into();
+        let local_ray = Ray3d {
+            origin: isometry.inverse_transform_point(ray.origin),
+            direction: isometry.inverse_transform_vector(ray.direction),
+        };
+        self.local_cast_ray(local_ray, max_distance)
+            .map(|hit| RayHit3d {
+                distance: hit.distance,
+                normal: isometry.transform_vector(hit.normal),
+            })
+    }
+
+    /// Returns `true` if `ray` hits the shape placed by `isometry` within `max_distance`
+    #[inline(always)]
+    fn intersects_ray(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        ray: Ray3d,
+        max_distance: f32,
+    ) -> bool {
+        self.cast_ray(isometry, ray, max_distance).is_some()
+    }
+}
+
+/// Overlap tests between two 3D shapes, each placed in the world by its own [`Isometry3d`]
+pub trait IntersectionQuery3d<Other: ?Sized = Self> {
+    /// Returns `true` if the shape placed by `isometry` overlaps `other` placed by `other_isometry`.
+    ///
+    /// Shapes that only touch count as intersecting.
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        other: &Other,
+        other_isometry: impl Into<Isometry3d>,
+    ) -> bool;
+}
+
+#[inline(always)]
+fn inside_hit(ray: Ray3d) -> Option<RayHit3d> {
+    Some(RayHit3d {
+        distance: 0.0,
+        normal: -ray.direction,
+    })
+}
+
+impl PointQuery3d for Sphere {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec3) -> bool {
+        point.length_squared() <= self.radius * self.radius
+    }
+
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec3) -> Vec3 {
+        point.clamp_length_max(self.radius)
+    }
+}
+
+impl RayCast3d for Sphere {
+    fn local_cast_ray(&self, ray: Ray3d, max_distance: f32) -> Option<RayHit3d> {
+        let b = ray.origin.dot(ray.direction);
+        let c = ray.origin.length_squared() - self.radius * self.radius;
+        if c <= 0.0 {
+            return inside_hit(ray);
+        }
+        let discriminant = b * b - c;
+        if b > 0.0 || discriminant < 0.0 {
+            return None;
+        }
+
+        let distance = -b - discriminant.sqrt();
+        (distance <= max_distance).then(|| RayHit3d {
+            distance,
+            normal: ray.get_point(distance).normalize(),
+        })
+    }
+}
+
+impl PointQuery3d for Cuboid {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec3) -> bool {
+        point.abs().cmple(self.half_size).all()
+    }
+
+    /// If the point is outside the cuboid, the returned point will be on its surface.
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec3) -> Vec3 {
+        point.clamp(-self.half_size, self.half_size)
+    }
+}
+
+impl RayCast3d for Cuboid {
+    fn local_cast_ray(&self, ray: Ray3d, max_distance: f32) -> Option<RayHit3d> {
+        if self.local_contains_point(ray.origin) {
+            return inside_hit(ray);
+        }
+
+        // Slab test, the entry distance is the largest of the per-axis entry distances
+        let inverse = ray.direction.recip();
+        let t1 = (-self.half_size - ray.origin) * inverse;
+        let t2 = (self.half_size - ray.origin) * inverse;
+        let near = t1.min(t2);
+        let far = t1.max(t2);
+        let distance = near.max_element();
+        if distance > far.min_element() || distance < 0.0 || distance > max_distance {
+            return None;
+        }
+
+        let axis = if near.x >= near.y && near.x >= near.z {
+            Vec3::X
+        } else if near.y >= near.z {
+            Vec3::Y
+        } else {
+            Vec3::Z
+        };
+        Some(RayHit3d {
+            distance,
+            normal: -axis * ray.direction.dot(axis).signum(),
+        })
+    }
+}
+
+impl PointQuery3d for Cylinder {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec3) -> bool {
+        point.y.abs() <= self.half_height
+            && point.xz().length_squared() <= self.radius * self.radius
+    }
+
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec3) -> Vec3 {
+        // The cylinder is the product of a disc and an interval, so both can be clamped separately
+        let radial = point.xz().clamp_length_max(self.radius);
+        Vec3::new(
+            radial.x,
+            point.y.clamp(-self.half_height, self.half_height),
+            radial.y,
+        )
+    }
+}
+
+impl RayCast3d for Cylinder {
+    fn local_cast_ray(&self, ray: Ray3d, max_distance: f32) -> Option<RayHit3d> {
+        if self.local_contains_point(ray.origin) {
+            return inside_hit(ray);
+        }
+
+        let mut closest: Option<RayHit3d> = None;
+        let mut consider = |distance: f32, normal: Vec3| {
+            if (0.0..=max_distance).contains(&distance)
+                && closest.is_none_or(|hit| distance < hit.distance)
+            {
+                closest = Some(RayHit3d { distance, normal });
+            }
+        };
+
+        // The side, as an infinite cylinder around the Y axis
+        let origin = ray.origin.xz();
+        let direction = ray.direction.xz();
+        let a = direction.length_squared();
+        if a > f32::EPSILON {
+            let b = origin.dot(direction);
+            let c = origin.length_squared() - self.radius * self.radius;
+            let discriminant = b * b - a * c;
+            if discriminant >= 0.0 {
+                let distance = (-b - discriminant.sqrt()) / a;
+                let point = ray.get_point(distance);
+                if point.y.abs() <= self.half_height {
+                    consider(distance, Vec3::new(point.x, 0.0, point.z).normalize());
+                }
+            }
+        }
+
+        // The caps
+        if ray.direction.y.abs() > f32::EPSILON {
+            for y in [-self.half_height, self.half_height] {
+                let distance = (y - ray.origin.y) / ray.direction.y;
+                let point = ray.get_point(distance);
+                if point.xz().length_squared() <= self.radius * self.radius {
+                    consider(distance, Vec3::Y * y.signum());
+                }
+            }
+        }
+
+        closest
+    }
+}
+
+impl<const N: usize> PointQuery3d for Polyline3d<N> {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec3) -> bool {
+        closest_point_on_polyline(point, &self.vertices, false)
+            .is_some_and(|closest| closest.distance_squared(point) <= f32::EPSILON)
+    }
+
+    /// A polyline without vertices is treated as its local origin.
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec3) -> Vec3 {
+        closest_point_on_polyline(point, &self.vertices, false).unwrap_or(Vec3::ZERO)
+    }
+}
+
+impl PointQuery3d for BoxedPolyline3d {
+    #[inline(always)]
+    fn local_contains_point(&self, point: Vec3) -> bool {
+        closest_point_on_polyline(point, &self.vertices, false)
+            .is_some_and(|closest| closest.distance_squared(point) <= f32::EPSILON)
+    }
+
+    /// A polyline without vertices is treated as its local origin.
+    #[inline(always)]
+    fn local_closest_point(&self, point: Vec3) -> Vec3 {
+        closest_point_on_polyline(point, &self.vertices, false).unwrap_or(Vec3::ZERO)
+    }
+}
+
+impl<S: PointQuery3d> IntersectionQuery3d<S> for Sphere {
+    #[inline(always)]
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        other: &S,
+        other_isometry: impl Into<Isometry3d>,
+    ) -> bool {
+        other.distance_to_point(other_isometry, isometry.into().translation) <= self.radius
+    }
+}
+
+impl IntersectionQuery3d for Cuboid {
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        other: &Cuboid,
+        other_isometry: impl Into<Isometry3d>,
+    ) -> bool {
+        let (isometry, other_isometry) = (isometry.into(), other_isometry.into());
+        let a_axes = [Vec3::X, Vec3::Y, Vec3::Z].map(|axis| isometry.transform_vector(axis));
+        let b_axes = [Vec3::X, Vec3::Y, Vec3::Z].map(|axis| other_isometry.transform_vector(axis));
+        let offset = other_isometry.translation - isometry.translation;
+
+        // Separating axis test on the face normals of both boxes and their pairwise edge crosses
+        let radius = |axes: &[Vec3; 3], half_size: Vec3, axis: Vec3| {
+            (0..3).map(|i| half_size[i] * axes[i].dot(axis).abs()).sum::<f32>()
+        };
+        let edge_axes = a_axes
+            .iter()
+            .flat_map(|a| b_axes.iter().map(move |b| a.cross(*b)))
+            // Parallel edges give no new axis, the face normals already cover them
+            .filter(|axis| axis.length_squared() > f32::EPSILON);
+        a_axes
+            .into_iter()
+            .chain(b_axes)
+            .chain(edge_axes)
+            .all(|axis| {
+                offset.dot(axis).abs()
+                    <= radius(&a_axes, self.half_size, axis)
+                        + radius(&b_axes, other.half_size, axis)
+            })
+    }
+}
+
+macro_rules! impl_intersects_sphere {
+    ($($shape:ty),*) => {
+        $(
+            impl IntersectionQuery3d<Sphere> for $shape {
+                #[inline(always)]
+                fn intersects(
+                    &self,
+                    isometry: impl Into<Isometry3d>,
+                    other: &Sphere,
+                    other_isometry: impl Into<Isometry3d>,
+                ) -> bool {
+                    other.intersects(other_isometry, self, isometry)
+                }
+            }
+        )*
+    };
+}
+
+impl_intersects_sphere!(Cuboid, Cylinder, BoxedPolyline3d);
+
+impl<const N: usize> IntersectionQuery3d<Sphere> for Polyline3d<N> {
+    #[inline(always)]
+    fn intersects(
+        &self,
+        isometry: impl Into<Isometry3d>,
+        other: &Sphere,
+        other_isometry: impl Into<Isometry3d>,
+    ) -> bool {
+        other.intersects(other_isometry, self, isometry)
+    }
+}
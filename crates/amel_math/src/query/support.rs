@@ -0,0 +1,109 @@
+use crate::prelude::*;
+
+/// A convex 3D shape described by its support function.
+///
+/// This is all the GJK and EPA queries need to know about a shape.
+pub trait SupportMap3d {
+    /// Get the point of the shape furthest along `direction`, both in the shape's local space
+    fn local_support_point(&self, direction: Vec3) -> Vec3;
+
+    /// Get the point of the shape placed by `isometry` furthest along `direction`
+    #[inline(always)]
+    fn support_point(&self, isometry: Isometry3d, direction: Vec3) -> Vec3 {
+        isometry
+            .transform_point(self.local_support_point(isometry.inverse_transform_vector(direction)))
+    }
+}
+
+/// The Minkowski sum of two convex shapes sharing the same local space.
+///
+/// For example, a [`Cuboid`] plus a [`Sphere`] is a box with rounded edges.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MinkowskiSum<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> MinkowskiSum<A, B> {
+    #[inline(always)]
+    pub const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+/// Get the point of `points` furthest along `direction`.
+///
+/// An empty set of points is treated as the local origin.
+fn point_cloud_support(points: &[Vec3], direction: Vec3) -> Vec3 {
+    points
+        .iter()
+        .copied()
+        .max_by(|a, b| a.dot(direction).total_cmp(&b.dot(direction)))
+        .unwrap_or(Vec3::ZERO)
+}
+
+impl SupportMap3d for Sphere {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        direction.normalize_or_zero() * self.radius
+    }
+}
+
+impl SupportMap3d for Cuboid {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        self.half_size.copysign(direction)
+    }
+}
+
+impl SupportMap3d for Cylinder {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        let radial = direction.xz().normalize_or_zero() * self.radius;
+        Vec3::new(radial.x, self.half_height.copysign(direction.y), radial.y)
+    }
+}
+
+impl SupportMap3d for Triangle3d {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        point_cloud_support(&self.vertices, direction)
+    }
+}
+
+impl SupportMap3d for ConvexHull3d {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        point_cloud_support(&self.points, direction)
+    }
+}
+
+/// Polylines are queried as the convex hull of their vertices
+impl<const N: usize> SupportMap3d for Polyline3d<N> {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        point_cloud_support(&self.vertices, direction)
+    }
+}
+
+/// Polylines are queried as the convex hull of their vertices
+impl SupportMap3d for BoxedPolyline3d {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        point_cloud_support(&self.vertices, direction)
+    }
+}
+
+impl<A: SupportMap3d, B: SupportMap3d> SupportMap3d for MinkowskiSum<A, B> {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        self.a.local_support_point(direction) + self.b.local_support_point(direction)
+    }
+}
+
+impl<S: SupportMap3d + ?Sized> SupportMap3d for &S {
+    #[inline(always)]
+    fn local_support_point(&self, direction: Vec3) -> Vec3 {
+        (**self).local_support_point(direction)
+    }
+}
@@ -0,0 +1,69 @@
+use glam::{Vec2, Vec3};
+
+/// A ray in 2D space with a normalized direction
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray2d {
+    pub origin: Vec2,
+    pub direction: Vec2,
+}
+
+impl Ray2d {
+    /// Create a new `Ray2d`, normalizing `direction`
+    #[inline(always)]
+    pub fn new(origin: Vec2, direction: Vec2) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Get the point at `distance` along the ray
+    #[inline(always)]
+    pub fn get_point(&self, distance: f32) -> Vec2 {
+        self.origin + self.direction * distance
+    }
+}
+
+/// A ray in 3D space with a normalized direction
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray3d {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray3d {
+    /// Create a new `Ray3d`, normalizing `direction`
+    #[inline(always)]
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Get the point at `distance` along the ray
+    #[inline(always)]
+    pub fn get_point(&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+}
+
+/// The result of a successful 2D ray cast
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit2d {
+    /// The distance along the ray to the hit
+    pub distance: f32,
+    /// The surface normal at the hit, facing against the ray
+    pub normal: Vec2,
+}
+
+/// The result of a successful 3D ray cast
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit3d {
+    /// The distance along the ray to the hit
+    pub distance: f32,
+    /// The surface normal at the hit, facing against the ray
+    pub normal: Vec3,
+}
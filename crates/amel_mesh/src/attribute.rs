@@ -151,3 +151,151 @@ impl From<&VertexAttributeValues> for wgpu::VertexFormat {
         }
     }
 }
+
+/// A vertex attribute component that can be compared with a tolerance.
+trait Component: Copy {
+    fn close(&self, other: &Self, tolerance: f32) -> bool;
+
+    /// Combine weighted values, integers can't be averaged and take the heaviest value
+    fn blend(values: &[(Self, f32)]) -> Self {
+        values
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|&(value, _)| value)
+            .expect("blending needs at least one value")
+    }
+}
+
+impl Component for f32 {
+    #[inline(always)]
+    fn close(&self, other: &Self, tolerance: f32) -> bool {
+        (self - other).abs() <= tolerance
+    }
+
+    fn blend(values: &[(Self, f32)]) -> Self {
+        values.iter().map(|&(value, weight)| value * weight).sum()
+    }
+}
+
+macro_rules! impl_exact_component {
+    ($($ty:ty),*) => {
+        $(
+            impl Component for $ty {
+                #[inline(always)]
+                fn close(&self, other: &Self, _tolerance: f32) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_component!(i32, u32, i16, u16, i8, u8);
+
+impl<T: Component, const N: usize> Component for [T; N] {
+    #[inline(always)]
+    fn close(&self, other: &Self, tolerance: f32) -> bool {
+        self.iter().zip(other).all(|(a, b)| a.close(b, tolerance))
+    }
+
+    fn blend(values: &[(Self, f32)]) -> Self {
+        std::array::from_fn(|i| {
+            let components: Vec<_> = values
+                .iter()
+                .map(|&(value, weight)| (value[i], weight))
+                .collect();
+            T::blend(&components)
+        })
+    }
+}
+
+macro_rules! impl_values_ops {
+    ($($variant:ident),* $(,)?) => {
+        impl VertexAttributeValues {
+            /// Append the values of `other`, returning `false` without changing anything
+            /// when the two have different formats.
+            pub fn extend(&mut self, other: &VertexAttributeValues) -> bool {
+                match (self, other) {
+                    $(
+                        (VertexAttributeValues::$variant(values), VertexAttributeValues::$variant(other)) => {
+                            values.extend_from_slice(other);
+                            true
+                        }
+                    )*
+                    _ => false,
+                }
+            }
+
+            /// Collect the values of the vertices in `indices`, in order and with repeats
+            pub fn gather(&self, indices: &[u32]) -> VertexAttributeValues {
+                match self {
+                    $(
+                        VertexAttributeValues::$variant(values) => VertexAttributeValues::$variant(
+                            indices.iter().map(|&index| values[index as usize]).collect(),
+                        ),
+                    )*
+                }
+            }
+
+            /// Check whether vertices `a` and `b` have the same value, allowing float
+            /// components to differ by up to `tolerance`
+            pub fn vertices_close(&self, a: usize, b: usize, tolerance: f32) -> bool {
+                match self {
+                    $(
+                        VertexAttributeValues::$variant(values) => {
+                            values[a].close(&values[b], tolerance)
+                        }
+                    )*
+                }
+            }
+
+            /// Create a value for every stencil from the weighted values of its vertices.
+            ///
+            /// Float components are blended, integer ones take the value with the largest
+            /// weight.
+            pub fn blend(&self, stencils: &[Vec<(u32, f32)>]) -> VertexAttributeValues {
+                match self {
+                    $(
+                        VertexAttributeValues::$variant(values) => VertexAttributeValues::$variant(
+                            stencils
+                                .iter()
+                                .map(|stencil| {
+                                    let weighted: Vec<_> = stencil
+                                        .iter()
+                                        .map(|&(index, weight)| (values[index as usize], weight))
+                                        .collect();
+                                    Component::blend(&weighted)
+                                })
+                                .collect(),
+                        ),
+                    )*
+                }
+            }
+
+            /// Swap the values of vertices `a` and `b`
+            pub fn swap(&mut self, a: usize, b: usize) {
+                match self {
+                    $(VertexAttributeValues::$variant(values) => values.swap(a, b),)*
+                }
+            }
+
+            /// Create `len` zeroed values with the same format as `self`
+            pub fn zeroed(&self, len: usize) -> VertexAttributeValues {
+                match self {
+                    $(
+                        VertexAttributeValues::$variant(_) => {
+                            VertexAttributeValues::$variant(vec![Default::default(); len])
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+impl_values_ops!(
+    Float32, Sint32, Uint32, Float32x2, Sint32x2, Uint32x2, Float32x3, Sint32x3, Uint32x3,
+    Float32x4, Sint32x4, Uint32x4, Sint16x2, Snorm16x2, Uint16x2, Unorm16x2, Sint16x4, Snorm16x4,
+    Uint16x4, Unorm16x4, Sint8x2, Snorm8x2, Uint8x2, Unorm8x2, Sint8x4, Snorm8x4, Uint8x4,
+    Unorm8x4,
+);
@@ -1,7 +1,9 @@
 use super::attribute::VertexAttributeValues;
 use amel_math::prelude::*;
+use thiserror::Error;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Error)]
+#[error("cannot convert {from:?} into {into}")]
 pub struct FromVertexAttributeError {
     from: VertexAttributeValues,
     into: &'static str,
@@ -22,7 +22,46 @@ pub struct GpuMesh {
     pub index_buffer: Option<Buffer>,
 }
 
+/// The parts of a pipeline's primitive state that a mesh has to be drawn with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeshPrimitiveKey {
+    pub topology: wgpu::PrimitiveTopology,
+    /// The index format of indexed strips, `None` for everything else
+    pub strip_index_format: Option<wgpu::IndexFormat>,
+}
+
+impl MeshPrimitiveKey {
+    /// Get every key a pipeline can be built for with `topology`
+    pub fn all(topology: wgpu::PrimitiveTopology) -> Vec<MeshPrimitiveKey> {
+        let formats: &[_] = if topology.is_strip() {
+            &[
+                None,
+                Some(wgpu::IndexFormat::Uint16),
+                Some(wgpu::IndexFormat::Uint32),
+            ]
+        } else {
+            &[None]
+        };
+        formats
+            .iter()
+            .map(|&strip_index_format| MeshPrimitiveKey {
+                topology,
+                strip_index_format,
+            })
+            .collect()
+    }
+}
+
 impl GpuMesh {
+    /// Get the primitive state this mesh has to be drawn with
+    pub fn primitive_key(&self) -> MeshPrimitiveKey {
+        let indexed_strip = self.primitive_topology.is_strip() && self.index_buffer.is_some();
+        MeshPrimitiveKey {
+            topology: self.primitive_topology,
+            strip_index_format: indexed_strip.then_some(self.index_format),
+        }
+    }
+
     pub fn buffer(&self, location: u32) -> Option<&Buffer> {
         self.vertex_buffers.get(&location)
     }
@@ -47,7 +86,7 @@ impl GpuMesh {
 
         match &self.index_buffer {
             Some(index_buffer) => {
-                encoder.set_index_buffer(index_buffer.slice(), wgpu::IndexFormat::Uint16);
+                encoder.set_index_buffer(index_buffer.slice(), self.index_format);
                 encoder.draw_indexed(0..index_buffer.count() as u32, 0, 0..1);
             }
             None => {
@@ -123,11 +162,21 @@ impl<'a> DrawMesh<'a> for wgpu::RenderPass<'a> {
 // }
 
 pub trait ToGpuMesh {
+    /// Upload the mesh to the GPU.
+    ///
+    /// In debug builds the mesh is [validated](Mesh::validate) first and invalid meshes panic.
     fn to_gpu(&self, device: &wgpu::Device) -> GpuMesh;
 }
 
 impl ToGpuMesh for Mesh {
     fn to_gpu(&self, device: &wgpu::Device) -> GpuMesh {
+        // Malformed meshes draw garbage or trip wgpu validation far away from their source
+        if cfg!(debug_assertions) {
+            if let Err(error) = self.validate() {
+                panic!("cannot upload an invalid mesh: {error}");
+            }
+        }
+
         let mut vertex_buffers = HashMap::new();
 
         for (location, data) in self.attributes.iter() {
@@ -8,6 +8,28 @@ pub enum Indices {
 }
 
 impl Indices {
+    /// Store `indices` for `vertex_count` vertices in the smallest format that fits them.
+    ///
+    /// Strip restarts given as `u32::MAX` become the restart value of the chosen format.
+    pub fn fit(indices: Vec<u32>, vertex_count: usize) -> Indices {
+        if vertex_count < u16::MAX as usize {
+            Indices::U16(
+                indices
+                    .into_iter()
+                    .map(|index| {
+                        if index == u32::MAX {
+                            u16::MAX
+                        } else {
+                            index as u16
+                        }
+                    })
+                    .collect(),
+            )
+        } else {
+            Indices::U32(indices)
+        }
+    }
+
     pub fn as_bytes<T: Pod>(&self) -> &[T] {
         match self {
             Indices::U16(data) => bytemuck::cast_slice(data),
@@ -42,8 +64,50 @@ impl Indices {
             Indices::U32(data) => data.is_empty(),
         }
     }
+
+    /// Get the index that restarts strips in this format, widened to `u32`
+    pub fn restart_value(&self) -> u32 {
+        match self {
+            Indices::U16(_) => u16::MAX as u32,
+            Indices::U32(_) => u32::MAX,
+        }
+    }
+
+    /// Iterate over the indices widened to `u32`
+    pub fn iter(&self) -> IndicesIter<'_> {
+        match self {
+            Indices::U16(data) => IndicesIter::U16(data.iter()),
+            Indices::U32(data) => IndicesIter::U32(data.iter()),
+        }
+    }
+}
+
+/// An iterator over [`Indices`] that yields every index as a `u32`.
+pub enum IndicesIter<'a> {
+    U16(std::slice::Iter<'a, u16>),
+    U32(std::slice::Iter<'a, u32>),
+}
+
+impl Iterator for IndicesIter<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        match self {
+            IndicesIter::U16(iter) => iter.next().map(|&index| index as u32),
+            IndicesIter::U32(iter) => iter.next().copied(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IndicesIter::U16(iter) => iter.size_hint(),
+            IndicesIter::U32(iter) => iter.size_hint(),
+        }
+    }
 }
 
+impl ExactSizeIterator for IndicesIter<'_> {}
+
 impl From<Vec<u16>> for Indices {
     fn from(data: Vec<u16>) -> Self {
         Indices::U16(data)
@@ -0,0 +1,311 @@
+use super::MeshIoError;
+use crate::attribute::VertexAttributeValues;
+use crate::indices::Indices;
+use crate::mesh::Mesh;
+use crate::validation::MeshError;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+use gltf::mesh::util::ReadIndices;
+use gltf::mesh::Mode;
+use std::path::Path;
+
+/// How the alpha channel of a [`GltfMaterial`] is used.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum GltfAlphaMode {
+    /// Alpha is ignored.
+    #[default]
+    Opaque,
+    /// Fragments with an alpha below the cutoff are discarded.
+    Mask(f32),
+    /// Alpha blends with whatever is behind.
+    Blend,
+}
+
+/// The metallic-roughness parameters of a glTF material.
+///
+/// Textures aren't loaded, they are referenced by their index in the glTF document.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GltfMaterial {
+    pub name: Option<String>,
+    pub base_color: [f32; 4],
+    pub metallic: f32,
+    pub roughness: f32,
+    pub emissive: [f32; 3],
+    pub alpha_mode: GltfAlphaMode,
+    pub double_sided: bool,
+    pub base_color_texture: Option<usize>,
+    pub metallic_roughness_texture: Option<usize>,
+    pub normal_texture: Option<usize>,
+    pub occlusion_texture: Option<usize>,
+    pub emissive_texture: Option<usize>,
+}
+
+/// A part of a glTF mesh drawn with a single material.
+#[derive(Clone, Debug)]
+pub struct GltfPrimitive {
+    pub mesh: Mesh,
+    /// The index of the material in [`GltfAsset::materials`], `None` for the default material
+    pub material: Option<usize>,
+}
+
+/// A glTF mesh, made of one or more primitives.
+#[derive(Clone, Debug)]
+pub struct GltfMesh {
+    pub name: Option<String>,
+    pub primitives: Vec<GltfPrimitive>,
+}
+
+/// A node of the glTF scene graph.
+#[derive(Clone, Debug)]
+pub struct GltfNode {
+    pub name: Option<String>,
+    /// The transform relative to the parent node
+    pub transform: Mat4,
+    /// The index of the mesh in [`GltfAsset::meshes`]
+    pub mesh: Option<usize>,
+    /// The indices of the child nodes in [`GltfAsset::nodes`]
+    pub children: Vec<usize>,
+}
+
+/// A glTF scene, the roots of a node hierarchy.
+#[derive(Clone, Debug)]
+pub struct GltfScene {
+    pub name: Option<String>,
+    /// The indices of the root nodes in [`GltfAsset::nodes`]
+    pub nodes: Vec<usize>,
+}
+
+/// The meshes, materials and scene graph of a glTF file.
+///
+/// Everything keeps the index it has in the glTF document.
+#[derive(Clone, Debug, Default)]
+pub struct GltfAsset {
+    pub meshes: Vec<GltfMesh>,
+    pub materials: Vec<GltfMaterial>,
+    pub nodes: Vec<GltfNode>,
+    pub scenes: Vec<GltfScene>,
+    /// The scene to show when the file doesn't say otherwise
+    pub default_scene: Option<usize>,
+}
+
+impl GltfAsset {
+    /// Get every mesh placed in `scene` with the world transform of its node.
+    ///
+    /// The meshes are referenced by their index in [`GltfAsset::meshes`], in depth first order.
+    pub fn mesh_instances(&self, scene: usize) -> Vec<(usize, Mat4)> {
+        let mut instances = Vec::new();
+        let mut stack: Vec<_> = self.scenes[scene]
+            .nodes
+            .iter()
+            .rev()
+            .map(|&node| (node, Mat4::IDENTITY))
+            .collect();
+        while let Some((index, parent)) = stack.pop() {
+            let node = &self.nodes[index];
+            let transform = parent * node.transform;
+            if let Some(mesh) = node.mesh {
+                instances.push((mesh, transform));
+            }
+            stack.extend(node.children.iter().rev().map(|&child| (child, transform)));
+        }
+        instances
+    }
+}
+
+/// Read a `.gltf` or `.glb` file.
+///
+/// Buffers can be in the GLB binary chunk, in data URIs, or in files next to `path`.
+pub fn load_gltf(path: impl AsRef<Path>) -> Result<GltfAsset, MeshIoError> {
+    let path = path.as_ref();
+    let gltf::Gltf { document, blob } = gltf::Gltf::open(path)?;
+    let buffers = gltf::import_buffers(&document, path.parent(), blob)?;
+    read_document(&document, &buffers)
+}
+
+/// Read a glTF or GLB file from memory.
+///
+/// Only buffers in the GLB binary chunk or in data URIs can be resolved, files referenced by
+/// the document fail to load.
+pub fn read_gltf(bytes: &[u8]) -> Result<GltfAsset, MeshIoError> {
+    let gltf::Gltf { document, blob } = gltf::Gltf::from_slice(bytes)?;
+    let buffers = gltf::import_buffers(&document, None, blob)?;
+    read_document(&document, &buffers)
+}
+
+fn read_document(
+    document: &gltf::Document,
+    buffers: &[gltf::buffer::Data],
+) -> Result<GltfAsset, MeshIoError> {
+    let meshes = document
+        .meshes()
+        .map(|mesh| {
+            Ok(GltfMesh {
+                name: mesh.name().map(str::to_string),
+                primitives: mesh
+                    .primitives()
+                    .map(|primitive| read_primitive(&primitive, buffers))
+                    .collect::<Result<_, MeshIoError>>()?,
+            })
+        })
+        .collect::<Result<_, MeshIoError>>()?;
+
+    let materials = document.materials().map(|m| read_material(&m)).collect();
+    let nodes = document
+        .nodes()
+        .map(|node| GltfNode {
+            name: node.name().map(str::to_string),
+            transform: Mat4::from_cols_array_2d(&node.transform().matrix()),
+            mesh: node.mesh().map(|mesh| mesh.index()),
+            children: node.children().map(|child| child.index()).collect(),
+        })
+        .collect();
+    let scenes = document
+        .scenes()
+        .map(|scene| GltfScene {
+            name: scene.name().map(str::to_string),
+            nodes: scene.nodes().map(|node| node.index()).collect(),
+        })
+        .collect();
+
+    Ok(GltfAsset {
+        meshes,
+        materials,
+        nodes,
+        scenes,
+        default_scene: document.default_scene().map(|scene| scene.index()),
+    })
+}
+
+/// Read the attributes and indices of a primitive.
+///
+/// Line loops and triangle fans have no wgpu topology and become indexed line strips and
+/// triangle lists.
+fn read_primitive(
+    primitive: &gltf::Primitive,
+    buffers: &[gltf::buffer::Data],
+) -> Result<GltfPrimitive, MeshIoError> {
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+
+    let positions: Vec<[f32; 3]> = reader
+        .read_positions()
+        .ok_or(MeshError::MissingAttribute {
+            location: Mesh::ATTRIBUTE_POSITION,
+        })?
+        .collect();
+    let vertex_count = positions.len();
+
+    let topology = match primitive.mode() {
+        Mode::Points => wgpu::PrimitiveTopology::PointList,
+        Mode::Lines => wgpu::PrimitiveTopology::LineList,
+        Mode::LineStrip | Mode::LineLoop => wgpu::PrimitiveTopology::LineStrip,
+        Mode::Triangles | Mode::TriangleFan => wgpu::PrimitiveTopology::TriangleList,
+        Mode::TriangleStrip => wgpu::PrimitiveTopology::TriangleStrip,
+    };
+    let mut mesh = Mesh::new(topology);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    if let Some(normals) = reader.read_normals() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals.collect::<Vec<_>>());
+    }
+    if let Some(tangents) = reader.read_tangents() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents.collect::<Vec<_>>());
+    }
+    if let Some(uvs) = reader.read_tex_coords(0) {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs.into_f32().collect::<Vec<_>>());
+    }
+    if let Some(uvs) = reader.read_tex_coords(1) {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_1, uvs.into_f32().collect::<Vec<_>>());
+    }
+    if let Some(colors) = reader.read_colors(0) {
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_COLOR,
+            colors.into_rgba_f32().collect::<Vec<_>>(),
+        );
+    }
+    if let Some(joints) = reader.read_joints(0) {
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_JOINT_INDEX,
+            VertexAttributeValues::Uint16x4(joints.into_u16().collect()),
+        );
+    }
+    if let Some(weights) = reader.read_weights(0) {
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_JOINT_WEIGHT,
+            weights.into_f32().collect::<Vec<_>>(),
+        );
+    }
+
+    let indices = match reader.read_indices() {
+        Some(ReadIndices::U8(indices)) => Some(Indices::U16(indices.map(u16::from).collect())),
+        Some(ReadIndices::U16(indices)) => Some(Indices::U16(indices.collect())),
+        Some(ReadIndices::U32(indices)) => Some(Indices::U32(indices.collect())),
+        None => None,
+    };
+    mesh.indices = match primitive.mode() {
+        Mode::LineLoop | Mode::TriangleFan => {
+            let elements: Vec<u32> = match &indices {
+                Some(indices) => indices.iter().collect(),
+                None => (0..vertex_count as u32).collect(),
+            };
+            let elements = if primitive.mode() == Mode::LineLoop {
+                close_loop(elements)
+            } else {
+                unroll_fan(&elements)
+            };
+            Some(Indices::fit(elements, vertex_count))
+        }
+        _ => indices,
+    };
+
+    Ok(GltfPrimitive {
+        mesh,
+        material: primitive.material().index(),
+    })
+}
+
+fn read_material(material: &gltf::Material) -> GltfMaterial {
+    let pbr = material.pbr_metallic_roughness();
+    GltfMaterial {
+        name: material.name().map(str::to_string),
+        base_color: pbr.base_color_factor(),
+        metallic: pbr.metallic_factor(),
+        roughness: pbr.roughness_factor(),
+        emissive: material.emissive_factor(),
+        alpha_mode: match material.alpha_mode() {
+            gltf::material::AlphaMode::Opaque => GltfAlphaMode::Opaque,
+            gltf::material::AlphaMode::Mask => {
+                GltfAlphaMode::Mask(material.alpha_cutoff().unwrap_or(0.5))
+            }
+            gltf::material::AlphaMode::Blend => GltfAlphaMode::Blend,
+        },
+        double_sided: material.double_sided(),
+        base_color_texture: pbr.base_color_texture().map(|info| info.texture().index()),
+        metallic_roughness_texture: pbr
+            .metallic_roughness_texture()
+            .map(|info| info.texture().index()),
+        normal_texture: material.normal_texture().map(|info| info.texture().index()),
+        occlusion_texture: material
+            .occlusion_texture()
+            .map(|info| info.texture().index()),
+        emissive_texture: material
+            .emissive_texture()
+            .map(|info| info.texture().index()),
+    }
+}
+
+/// Repeat the first vertex of a line loop at its end
+fn close_loop(mut elements: Vec<u32>) -> Vec<u32> {
+    if let Some(&first) = elements.first() {
+        elements.push(first);
+    }
+    elements
+}
+
+/// Split a triangle fan into separate triangles around its first vertex
+fn unroll_fan(elements: &[u32]) -> Vec<u32> {
+    elements
+        .windows(2)
+        .skip(1)
+        .flat_map(|pair| [elements[0], pair[0], pair[1]])
+        .collect()
+}
@@ -0,0 +1,42 @@
+pub mod gltf;
+pub mod obj;
+pub mod ply;
+pub mod stl;
+
+pub use self::gltf::*;
+pub use obj::*;
+pub use ply::*;
+pub use stl::*;
+
+use super::validation::MeshError;
+
+use thiserror::Error;
+
+/// An error raised while reading or writing a mesh file.
+#[derive(Debug, Error)]
+pub enum MeshIoError {
+    /// The file couldn't be read or written.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The file is malformed.
+    #[error("line {line}: {message}")]
+    Parse { line: usize, message: String },
+    /// The binary data or the structure of the file is malformed.
+    #[error("{0}")]
+    Malformed(String),
+    /// The glTF document or its buffers couldn't be read.
+    #[error(transparent)]
+    Gltf(#[from] ::gltf::Error),
+    /// The mesh can't be stored in the file format.
+    #[error(transparent)]
+    Mesh(#[from] MeshError),
+}
+
+impl MeshIoError {
+    pub(crate) fn parse(line: usize, message: impl Into<String>) -> Self {
+        MeshIoError::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,567 @@
+use super::MeshIoError;
+use crate::attribute::VertexAttributeValues;
+use crate::indices::Indices;
+use crate::mesh::Mesh;
+
+use amel_gpu::prelude::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// How the faces of an OBJ file are split into meshes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ObjSplit {
+    /// Put every face into a single mesh.
+    None,
+    /// Start a new mesh for every object or group, and whenever the material changes inside
+    /// one, so every mesh has a single material.
+    #[default]
+    Group,
+    /// Put all faces with the same material into one mesh named after the material, ignoring
+    /// objects and groups.
+    Material,
+}
+
+/// A material from an MTL library.
+///
+/// Only the colors and texture names are kept, texture paths are relative to the library.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjMaterial {
+    pub name: String,
+    /// `Ka`
+    pub ambient: Option<[f32; 3]>,
+    /// `Kd`
+    pub diffuse: Option<[f32; 3]>,
+    /// `Ks`
+    pub specular: Option<[f32; 3]>,
+    /// `Ke`
+    pub emissive: Option<[f32; 3]>,
+    /// `Ns`
+    pub shininess: Option<f32>,
+    /// `d`, or one minus `Tr`
+    pub opacity: Option<f32>,
+    /// `map_Ka`
+    pub ambient_texture: Option<String>,
+    /// `map_Kd`
+    pub diffuse_texture: Option<String>,
+    /// `map_Ks`
+    pub specular_texture: Option<String>,
+    /// `map_Ke`
+    pub emissive_texture: Option<String>,
+    /// `map_Bump`, `bump` or `norm`
+    pub normal_texture: Option<String>,
+    /// `map_d`
+    pub opacity_texture: Option<String>,
+}
+
+/// A mesh read from, or to be written to, an OBJ file.
+#[derive(Clone, Debug)]
+pub struct ObjModel {
+    /// The name of the object or group, empty when the file doesn't name it
+    pub name: String,
+    /// The name of the material the faces use
+    pub material: Option<String>,
+    pub mesh: Mesh,
+}
+
+/// The meshes and materials of an OBJ file.
+#[derive(Clone, Debug, Default)]
+pub struct ObjScene {
+    pub models: Vec<ObjModel>,
+    pub materials: Vec<ObjMaterial>,
+    /// The `mtllib` paths, relative to the OBJ file
+    pub material_libraries: Vec<String>,
+}
+
+impl ObjScene {
+    /// Find a material by name
+    pub fn material(&self, name: &str) -> Option<&ObjMaterial> {
+        self.materials.iter().find(|material| material.name == name)
+    }
+}
+
+/// Read an OBJ file and the material libraries it names.
+///
+/// Libraries that can't be opened are skipped with a warning, so the geometry is still usable
+/// when the materials went missing.
+pub fn load_obj(path: impl AsRef<Path>, split: ObjSplit) -> Result<ObjScene, MeshIoError> {
+    let path = path.as_ref();
+    let mut scene = read_obj(BufReader::new(File::open(path)?), split)?;
+
+    let directory = path.parent().unwrap_or(Path::new(""));
+    for library in &scene.material_libraries {
+        match File::open(directory.join(library)) {
+            Ok(file) => scene.materials.extend(read_mtl(BufReader::new(file))?),
+            Err(error) => log::warn!("skipping material library {library}: {error}"),
+        }
+    }
+    Ok(scene)
+}
+
+/// Read the geometry of an OBJ file.
+///
+/// `v`, `vt` and `vn` become [`Mesh::ATTRIBUTE_POSITION`], [`Mesh::ATTRIBUTE_UV_0`] and
+/// [`Mesh::ATTRIBUTE_NORMAL`], and `v x y z r g b` colors become [`Mesh::ATTRIBUTE_COLOR`].
+/// Texture coordinates are flipped vertically to put their origin at the top left. Polygons
+/// are triangulated as fans, so they are assumed to be convex, and lines and points are
+/// skipped. Every mesh is an indexed `TriangleList` that only has the attributes its faces
+/// use, material libraries are listed in [`ObjScene::material_libraries`] but not read.
+pub fn read_obj(reader: impl BufRead, split: ObjSplit) -> Result<ObjScene, MeshIoError> {
+    let mut positions = Vec::new();
+    let mut colors = Vec::new();
+    let mut uvs = Vec::new();
+    let mut normals = Vec::new();
+    let mut material_libraries = Vec::new();
+
+    let mut parts = vec![Part::default()];
+    let mut current = 0;
+    let mut name = String::new();
+    let mut material = None;
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let number = number + 1;
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+
+        match keyword {
+            "v" => {
+                let values = parse_floats(tokens, number)?;
+                match values.len() {
+                    3 | 4 => colors.push(None),
+                    6 | 7 => colors.push(Some([values[3], values[4], values[5], 1.0])),
+                    _ => return Err(MeshIoError::parse(number, "expected 3 or 6 coordinates")),
+                }
+                positions.push([values[0], values[1], values[2]]);
+            }
+            "vt" => {
+                let values = parse_floats(tokens, number)?;
+                let Some(&u) = values.first() else {
+                    return Err(MeshIoError::parse(number, "missing texture coordinate"));
+                };
+                uvs.push([u, 1.0 - values.get(1).copied().unwrap_or(0.0)]);
+            }
+            "vn" => {
+                let values = parse_floats(tokens, number)?;
+                if values.len() != 3 {
+                    return Err(MeshIoError::parse(number, "expected 3 normal components"));
+                }
+                normals.push([values[0], values[1], values[2]]);
+            }
+            "f" => {
+                let corners = tokens
+                    .map(|token| {
+                        parse_corner(token, [positions.len(), uvs.len(), normals.len()], number)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if corners.len() < 3 {
+                    return Err(MeshIoError::parse(number, "faces need at least 3 vertices"));
+                }
+
+                current = match split {
+                    ObjSplit::None => current,
+                    ObjSplit::Group => {
+                        let part = &parts[current];
+                        if !part.indices.is_empty()
+                            && (part.name != name || part.material != material)
+                        {
+                            parts.push(Part::default());
+                            parts.len() - 1
+                        } else {
+                            current
+                        }
+                    }
+                    ObjSplit::Material => {
+                        match parts
+                            .iter()
+                            .position(|part| part.material == material && !part.indices.is_empty())
+                        {
+                            Some(index) => index,
+                            None if parts[current].indices.is_empty() => current,
+                            None => {
+                                parts.push(Part::default());
+                                parts.len() - 1
+                            }
+                        }
+                    }
+                };
+                let part = &mut parts[current];
+                if part.indices.is_empty() {
+                    part.name = match split {
+                        ObjSplit::Material => material.clone().unwrap_or_default(),
+                        _ => name.clone(),
+                    };
+                    part.material.clone_from(&material);
+                }
+
+                let corners: Vec<_> = corners.into_iter().map(|c| part.vertex(c)).collect();
+                for i in 1..corners.len() - 1 {
+                    part.indices
+                        .extend_from_slice(&[corners[0], corners[i], corners[i + 1]]);
+                }
+            }
+            "o" | "g" => name = rest(&line, keyword),
+            "usemtl" => material = Some(rest(&line, keyword)),
+            "mtllib" => material_libraries.push(rest(&line, keyword)),
+            _ => {}
+        }
+    }
+
+    let models = parts
+        .into_iter()
+        .filter(|part| !part.indices.is_empty())
+        .map(|part| part.into_model(&positions, &colors, &uvs, &normals))
+        .collect();
+    Ok(ObjScene {
+        models,
+        materials: Vec::new(),
+        material_libraries,
+    })
+}
+
+/// Read the materials of an MTL library
+pub fn read_mtl(reader: impl BufRead) -> Result<Vec<ObjMaterial>, MeshIoError> {
+    let mut materials: Vec<ObjMaterial> = Vec::new();
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let number = number + 1;
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+
+        if keyword == "newmtl" {
+            materials.push(ObjMaterial {
+                name: rest(&line, keyword),
+                ..Default::default()
+            });
+            continue;
+        }
+        if keyword.starts_with('#') {
+            continue;
+        }
+        let Some(material) = materials.last_mut() else {
+            return Err(MeshIoError::parse(number, "statement before newmtl"));
+        };
+
+        let color = |tokens| -> Result<Option<[f32; 3]>, MeshIoError> {
+            let values = parse_floats(tokens, number)?;
+            match values[..] {
+                [r] => Ok(Some([r; 3])),
+                [r, g, b] => Ok(Some([r, g, b])),
+                _ => Err(MeshIoError::parse(
+                    number,
+                    "expected 1 or 3 color components",
+                )),
+            }
+        };
+        let scalar = |tokens| -> Result<Option<f32>, MeshIoError> {
+            match parse_floats(tokens, number)?[..] {
+                [value] => Ok(Some(value)),
+                _ => Err(MeshIoError::parse(number, "expected a single value")),
+            }
+        };
+
+        match keyword {
+            "Ka" => material.ambient = color(tokens)?,
+            "Kd" => material.diffuse = color(tokens)?,
+            "Ks" => material.specular = color(tokens)?,
+            "Ke" => material.emissive = color(tokens)?,
+            "Ns" => material.shininess = scalar(tokens)?,
+            "d" => material.opacity = scalar(tokens)?,
+            "Tr" => material.opacity = scalar(tokens)?.map(|t| 1.0 - t),
+            "map_Ka" => material.ambient_texture = texture_name(&line, keyword),
+            "map_Kd" => material.diffuse_texture = texture_name(&line, keyword),
+            "map_Ks" => material.specular_texture = texture_name(&line, keyword),
+            "map_Ke" => material.emissive_texture = texture_name(&line, keyword),
+            "map_Bump" | "map_bump" | "bump" | "norm" => {
+                material.normal_texture = texture_name(&line, keyword)
+            }
+            "map_d" => material.opacity_texture = texture_name(&line, keyword),
+            _ => {}
+        }
+    }
+    Ok(materials)
+}
+
+/// Write `models` as objects of one OBJ file, referencing `material_library` if given.
+///
+/// Triangle and line topologies are written as faces and lines, point lists as points.
+/// Positions are required, normals, [`Mesh::ATTRIBUTE_UV_0`] and `Float32x4` colors are written
+/// when present, and texture coordinates are flipped back to a bottom left origin.
+pub fn write_obj(
+    mut writer: impl Write,
+    models: &[ObjModel],
+    material_library: Option<&str>,
+) -> Result<(), MeshIoError> {
+    if let Some(library) = material_library {
+        writeln!(writer, "mtllib {library}")?;
+    }
+    let mut offsets = [1; 3];
+    for model in models {
+        if !model.name.is_empty() {
+            writeln!(writer, "o {}", model.name)?;
+        }
+        if let Some(material) = &model.material {
+            writeln!(writer, "usemtl {material}")?;
+        }
+        write_mesh(&mut writer, &model.mesh, &mut offsets)?;
+    }
+    Ok(())
+}
+
+/// Write `materials` as an MTL library
+pub fn write_mtl(mut writer: impl Write, materials: &[ObjMaterial]) -> Result<(), MeshIoError> {
+    for material in materials {
+        writeln!(writer, "newmtl {}", material.name)?;
+        let colors = [
+            ("Ka", material.ambient),
+            ("Kd", material.diffuse),
+            ("Ks", material.specular),
+            ("Ke", material.emissive),
+        ];
+        for (keyword, color) in colors {
+            if let Some([r, g, b]) = color {
+                writeln!(writer, "{keyword} {r} {g} {b}")?;
+            }
+        }
+        if let Some(shininess) = material.shininess {
+            writeln!(writer, "Ns {shininess}")?;
+        }
+        if let Some(opacity) = material.opacity {
+            writeln!(writer, "d {opacity}")?;
+        }
+        let textures = [
+            ("map_Ka", &material.ambient_texture),
+            ("map_Kd", &material.diffuse_texture),
+            ("map_Ks", &material.specular_texture),
+            ("map_Ke", &material.emissive_texture),
+            ("map_Bump", &material.normal_texture),
+            ("map_d", &material.opacity_texture),
+        ];
+        for (keyword, texture) in textures {
+            if let Some(texture) = texture {
+                writeln!(writer, "{keyword} {texture}")?;
+            }
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+impl Mesh {
+    /// Write this mesh as a single OBJ object, see [`write_obj`]
+    pub fn write_obj(&self, mut writer: impl Write) -> Result<(), MeshIoError> {
+        write_mesh(&mut writer, self, &mut [1; 3])
+    }
+}
+
+/// The faces of one output mesh, with the vertices for every distinct `v/vt/vn` combination
+#[derive(Default)]
+struct Part {
+    name: String,
+    material: Option<String>,
+    vertices: HashMap<Corner, u32>,
+    corners: Vec<Corner>,
+    indices: Vec<u32>,
+}
+
+/// The zero based position, texture coordinate and normal indices of a face corner
+type Corner = (usize, Option<usize>, Option<usize>);
+
+impl Part {
+    fn vertex(&mut self, corner: Corner) -> u32 {
+        *self.vertices.entry(corner).or_insert_with(|| {
+            self.corners.push(corner);
+            self.corners.len() as u32 - 1
+        })
+    }
+
+    fn into_model(
+        self,
+        positions: &[[f32; 3]],
+        colors: &[Option<[f32; 4]>],
+        uvs: &[[f32; 2]],
+        normals: &[[f32; 3]],
+    ) -> ObjModel {
+        let mut mesh = Mesh::new(wgpu::PrimitiveTopology::TriangleList);
+        let corners = &self.corners;
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            corners.iter().map(|c| positions[c.0]).collect::<Vec<_>>(),
+        );
+        if corners.iter().any(|c| colors[c.0].is_some()) {
+            mesh.insert_attribute(
+                Mesh::ATTRIBUTE_COLOR,
+                corners
+                    .iter()
+                    .map(|c| colors[c.0].unwrap_or([1.0; 4]))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        if corners.iter().any(|c| c.1.is_some()) {
+            mesh.insert_attribute(
+                Mesh::ATTRIBUTE_UV_0,
+                corners
+                    .iter()
+                    .map(|c| c.1.map_or([0.0; 2], |i| uvs[i]))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        if corners.iter().any(|c| c.2.is_some()) {
+            mesh.insert_attribute(
+                Mesh::ATTRIBUTE_NORMAL,
+                corners
+                    .iter()
+                    .map(|c| c.2.map_or([0.0; 3], |i| normals[i]))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        mesh.insert_indices(Indices::fit(self.indices, corners.len()));
+
+        ObjModel {
+            name: self.name,
+            material: self.material,
+            mesh,
+        }
+    }
+}
+
+/// Parse a `v/vt/vn` face corner, resolving negative indices against the `counts` read so far
+fn parse_corner(token: &str, counts: [usize; 3], line: usize) -> Result<Corner, MeshIoError> {
+    let mut indices = [None; 3];
+    for (i, part) in token.split('/').enumerate() {
+        if i > 2 {
+            return Err(MeshIoError::parse(
+                line,
+                format!("invalid face vertex {token}"),
+            ));
+        }
+        if part.is_empty() {
+            continue;
+        }
+        let index: i64 = part
+            .parse()
+            .map_err(|_| MeshIoError::parse(line, format!("invalid index {part}")))?;
+        let resolved = match index {
+            1.. => index - 1,
+            ..=-1 => counts[i] as i64 + index,
+            0 => -1,
+        };
+        if resolved < 0 || resolved >= counts[i] as i64 {
+            return Err(MeshIoError::parse(
+                line,
+                format!("index {index} is out of range"),
+            ));
+        }
+        indices[i] = Some(resolved as usize);
+    }
+    match indices {
+        [Some(position), uv, normal] => Ok((position, uv, normal)),
+        _ => Err(MeshIoError::parse(
+            line,
+            format!("face vertex {token} has no position"),
+        )),
+    }
+}
+
+fn parse_floats<'a>(
+    tokens: impl Iterator<Item = &'a str>,
+    line: usize,
+) -> Result<Vec<f32>, MeshIoError> {
+    tokens
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| MeshIoError::parse(line, format!("invalid number {token}")))
+        })
+        .collect()
+}
+
+/// Get everything after the keyword, names may contain spaces
+fn rest(line: &str, keyword: &str) -> String {
+    line.trim_start()[keyword.len()..].trim().to_string()
+}
+
+/// Get the file name of a texture statement, skipping its options
+fn texture_name(line: &str, keyword: &str) -> Option<String> {
+    let mut tokens: Vec<_> = rest(line, keyword)
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    // Options are followed by one to three values, but the file name is always last
+    tokens.pop()
+}
+
+/// Write the vertices and elements of `mesh`, advancing the 1-based `offsets` of the
+/// positions, texture coordinates and normals
+fn write_mesh(
+    writer: &mut impl Write,
+    mesh: &Mesh,
+    offsets: &mut [usize; 3],
+) -> Result<(), MeshIoError> {
+    let positions = mesh.positions()?;
+    let uvs = mesh.float32x2_attribute(Mesh::ATTRIBUTE_UV_0).ok();
+    let normals = mesh.float32x3_attribute(Mesh::ATTRIBUTE_NORMAL).ok();
+    let colors = match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+        Some(VertexAttributeValues::Float32x4(colors)) => Some(colors),
+        _ => None,
+    };
+
+    for (i, [x, y, z]) in positions.iter().enumerate() {
+        match colors {
+            Some(colors) => {
+                let [r, g, b, _] = colors[i];
+                writeln!(writer, "v {x} {y} {z} {r} {g} {b}")?;
+            }
+            None => writeln!(writer, "v {x} {y} {z}")?,
+        }
+    }
+    for [u, v] in uvs.into_iter().flatten() {
+        writeln!(writer, "vt {u} {}", 1.0 - v)?;
+    }
+    for [x, y, z] in normals.into_iter().flatten() {
+        writeln!(writer, "vn {x} {y} {z}")?;
+    }
+
+    let corner = |vertex: u32| {
+        let vertex = vertex as usize;
+        let position = offsets[0] + vertex;
+        match (uvs.is_some(), normals.is_some()) {
+            (true, true) => format!("{position}/{}/{}", offsets[1] + vertex, offsets[2] + vertex),
+            (true, false) => format!("{position}/{}", offsets[1] + vertex),
+            (false, true) => format!("{position}//{}", offsets[2] + vertex),
+            (false, false) => position.to_string(),
+        }
+    };
+    match mesh.primitive_topology {
+        wgpu::PrimitiveTopology::TriangleList | wgpu::PrimitiveTopology::TriangleStrip => {
+            for [a, b, c] in mesh.triangles()? {
+                writeln!(writer, "f {} {} {}", corner(a), corner(b), corner(c))?;
+            }
+        }
+        wgpu::PrimitiveTopology::LineList | wgpu::PrimitiveTopology::LineStrip => {
+            for [a, b] in mesh.lines()? {
+                writeln!(
+                    writer,
+                    "l {} {}",
+                    offsets[0] + a as usize,
+                    offsets[0] + b as usize
+                )?;
+            }
+        }
+        wgpu::PrimitiveTopology::PointList => {
+            for vertex in 0..positions.len() {
+                writeln!(writer, "p {}", offsets[0] + vertex)?;
+            }
+        }
+    }
+
+    offsets[0] += positions.len();
+    offsets[1] += uvs.map_or(0, <[_]>::len);
+    offsets[2] += normals.map_or(0, <[_]>::len);
+    Ok(())
+}
@@ -0,0 +1,497 @@
+use super::MeshIoError;
+use crate::attribute::VertexAttributeValues;
+use crate::indices::Indices;
+use crate::mesh::Mesh;
+
+use amel_gpu::prelude::*;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// How the body of a PLY file is encoded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlyFormat {
+    Ascii,
+    #[default]
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+/// Read a PLY file, see [`read_ply`]
+pub fn load_ply(path: impl AsRef<Path>) -> Result<Mesh, MeshIoError> {
+    read_ply(BufReader::new(File::open(path)?))
+}
+
+/// Read an ASCII or binary PLY file.
+///
+/// `x`, `y` and `z` become [`Mesh::ATTRIBUTE_POSITION`], `nx`, `ny` and `nz`
+/// [`Mesh::ATTRIBUTE_NORMAL`], `s` and `t` or `u` and `v` [`Mesh::ATTRIBUTE_UV_0`], and `red`,
+/// `green`, `blue` and `alpha` [`Mesh::ATTRIBUTE_COLOR`] as `Float32x4`. Texture coordinates are
+/// flipped vertically to put their origin at the top left. Faces are triangulated as fans into
+/// an indexed `TriangleList`, files without faces become a `LineList` of their edges, and files
+/// with neither become a `PointList`. Other elements and properties are skipped.
+pub fn read_ply(mut reader: impl BufRead) -> Result<Mesh, MeshIoError> {
+    let (format, elements) = read_header(&mut reader)?;
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let mut body = match format {
+        PlyFormat::Ascii => Body::Ascii(
+            std::str::from_utf8(&bytes)
+                .map_err(|_| MeshIoError::Malformed("PLY body is not valid text".into()))?
+                .split_ascii_whitespace(),
+        ),
+        _ => Body::Binary {
+            bytes: &bytes,
+            big_endian: format == PlyFormat::BinaryBigEndian,
+        },
+    };
+
+    let mut vertex = None;
+    let mut faces = Vec::new();
+    let mut edges = Vec::new();
+    let mut corners = Vec::new();
+    for element in &elements {
+        let face_list = match element.name.as_str() {
+            "face" => element.position(&["vertex_indices", "vertex_index"]),
+            _ => None,
+        };
+        let mut columns = vec![Vec::new(); element.properties.len()];
+        for _ in 0..element.count {
+            for (i, property) in element.properties.iter().enumerate() {
+                match property.kind {
+                    PropertyKind::Scalar(scalar) => columns[i].push(body.next(scalar)?),
+                    PropertyKind::List { count, item } => {
+                        let count = body.next(count)? as usize;
+                        corners.clear();
+                        for _ in 0..count {
+                            corners.push(body.next(item)? as u32);
+                        }
+                        if face_list == Some(i) {
+                            for j in 1..count.saturating_sub(1) {
+                                faces.extend([corners[0], corners[j], corners[j + 1]]);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        match element.name.as_str() {
+            "vertex" => vertex = Some((element, columns)),
+            "edge" => {
+                if let (Some(a), Some(b)) = (
+                    element.position(&["vertex1"]),
+                    element.position(&["vertex2"]),
+                ) {
+                    for (&a, &b) in columns[a].iter().zip(&columns[b]) {
+                        edges.extend([a as u32, b as u32]);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (vertex, columns) =
+        vertex.ok_or_else(|| MeshIoError::Malformed("PLY file has no vertex element".into()))?;
+    let vertex_count = vertex.count;
+    let column = |names: &[&str]| -> Option<Vec<f32>> {
+        let index = vertex.position(names)?;
+        let PropertyKind::Scalar(scalar) = vertex.properties[index].kind else {
+            return None;
+        };
+        let scale = scalar.color_scale(names[0]);
+        Some(
+            columns[index]
+                .iter()
+                .map(|&value| (value * scale) as f32)
+                .collect(),
+        )
+    };
+
+    let (Some(x), Some(y), Some(z)) = (column(&["x"]), column(&["y"]), column(&["z"])) else {
+        return Err(MeshIoError::Malformed(
+            "PLY vertices have no x, y and z".into(),
+        ));
+    };
+    let topology = if !faces.is_empty() {
+        wgpu::PrimitiveTopology::TriangleList
+    } else if !edges.is_empty() {
+        wgpu::PrimitiveTopology::LineList
+    } else {
+        wgpu::PrimitiveTopology::PointList
+    };
+    let mut mesh = Mesh::new(topology);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, zip3(x, y, z));
+
+    if let (Some(x), Some(y), Some(z)) = (column(&["nx"]), column(&["ny"]), column(&["nz"])) {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, zip3(x, y, z));
+    }
+    let u = column(&["s", "u", "texture_u"]);
+    let v = column(&["t", "v", "texture_v"]);
+    if let (Some(u), Some(v)) = (u, v) {
+        let uvs: Vec<_> = u.into_iter().zip(v).map(|(u, v)| [u, 1.0 - v]).collect();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    }
+    let (red, green, blue) = (column(&["red"]), column(&["green"]), column(&["blue"]));
+    if let (Some(red), Some(green), Some(blue)) = (red, green, blue) {
+        let alpha = column(&["alpha"]).unwrap_or_else(|| vec![1.0; vertex_count]);
+        let colors: Vec<_> = (0..vertex_count)
+            .map(|i| [red[i], green[i], blue[i], alpha[i]])
+            .collect();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+
+    let indices = if faces.is_empty() { edges } else { faces };
+    if !indices.is_empty() {
+        if let Some(&index) = indices.iter().find(|&&i| i as usize >= vertex_count) {
+            return Err(MeshIoError::Malformed(format!(
+                "PLY index {index} is out of range for {} vertices",
+                vertex_count
+            )));
+        }
+        mesh.insert_indices(Indices::fit(indices, vertex_count));
+    }
+    Ok(mesh)
+}
+
+/// Write `mesh` as a PLY file.
+///
+/// Positions are required, normals, [`Mesh::ATTRIBUTE_UV_0`] and `Float32x4` or `Unorm8x4`
+/// colors are written when present, with colors stored as bytes. Triangle topologies are
+/// written as faces, line topologies as edges, and point lists as vertices alone.
+pub fn write_ply(
+    mut writer: impl Write,
+    mesh: &Mesh,
+    format: PlyFormat,
+) -> Result<(), MeshIoError> {
+    let positions = mesh.positions()?;
+    let normals = mesh.float32x3_attribute(Mesh::ATTRIBUTE_NORMAL).ok();
+    let uvs = mesh.float32x2_attribute(Mesh::ATTRIBUTE_UV_0).ok();
+    let colors: Option<Vec<[u8; 4]>> = match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+        Some(VertexAttributeValues::Float32x4(colors)) => Some(
+            colors
+                .iter()
+                .map(|color| color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8))
+                .collect(),
+        ),
+        Some(VertexAttributeValues::Unorm8x4(colors)) => Some(colors.clone()),
+        _ => None,
+    };
+    let (triangles, lines) = match mesh.primitive_topology {
+        wgpu::PrimitiveTopology::TriangleList | wgpu::PrimitiveTopology::TriangleStrip => {
+            (mesh.triangles()?, Vec::new())
+        }
+        wgpu::PrimitiveTopology::LineList | wgpu::PrimitiveTopology::LineStrip => {
+            (Vec::new(), mesh.lines()?)
+        }
+        wgpu::PrimitiveTopology::PointList => (Vec::new(), Vec::new()),
+    };
+
+    let format_name = match format {
+        PlyFormat::Ascii => "ascii",
+        PlyFormat::BinaryLittleEndian => "binary_little_endian",
+        PlyFormat::BinaryBigEndian => "binary_big_endian",
+    };
+    writeln!(writer, "ply\nformat {format_name} 1.0")?;
+    writeln!(writer, "element vertex {}", positions.len())?;
+    writeln!(
+        writer,
+        "property float x\nproperty float y\nproperty float z"
+    )?;
+    if normals.is_some() {
+        writeln!(
+            writer,
+            "property float nx\nproperty float ny\nproperty float nz"
+        )?;
+    }
+    if uvs.is_some() {
+        writeln!(writer, "property float s\nproperty float t")?;
+    }
+    if colors.is_some() {
+        writeln!(writer, "property uchar red\nproperty uchar green")?;
+        writeln!(writer, "property uchar blue\nproperty uchar alpha")?;
+    }
+    if !triangles.is_empty() {
+        writeln!(writer, "element face {}", triangles.len())?;
+        writeln!(writer, "property list uchar uint vertex_indices")?;
+    }
+    if !lines.is_empty() {
+        writeln!(writer, "element edge {}", lines.len())?;
+        writeln!(writer, "property uint vertex1\nproperty uint vertex2")?;
+    }
+    writeln!(writer, "end_header")?;
+
+    let mut row = Vec::new();
+    for (i, position) in positions.iter().enumerate() {
+        row.clear();
+        row.extend(position.map(Value::F32));
+        if let Some(normals) = normals {
+            row.extend(normals[i].map(Value::F32));
+        }
+        if let Some(uvs) = uvs {
+            let [u, v] = uvs[i];
+            row.extend([Value::F32(u), Value::F32(1.0 - v)]);
+        }
+        if let Some(colors) = &colors {
+            row.extend(colors[i].map(Value::U8));
+        }
+        write_row(&mut writer, format, &row)?;
+    }
+    for triangle in triangles {
+        row.clear();
+        row.push(Value::U8(3));
+        row.extend(triangle.map(Value::U32));
+        write_row(&mut writer, format, &row)?;
+    }
+    for line in lines {
+        write_row(&mut writer, format, &line.map(Value::U32))?;
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl Scalar {
+    fn parse(name: &str) -> Option<Scalar> {
+        Some(match name {
+            "char" | "int8" => Scalar::I8,
+            "uchar" | "uint8" => Scalar::U8,
+            "short" | "int16" => Scalar::I16,
+            "ushort" | "uint16" => Scalar::U16,
+            "int" | "int32" => Scalar::I32,
+            "uint" | "uint32" => Scalar::U32,
+            "float" | "float32" => Scalar::F32,
+            "double" | "float64" => Scalar::F64,
+            _ => return None,
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Scalar::I8 | Scalar::U8 => 1,
+            Scalar::I16 | Scalar::U16 => 2,
+            Scalar::I32 | Scalar::U32 | Scalar::F32 => 4,
+            Scalar::F64 => 8,
+        }
+    }
+
+    /// Get the factor that maps a value of the property `name` to the `0..=1` range of colors
+    fn color_scale(self, name: &str) -> f64 {
+        if !matches!(name, "red" | "green" | "blue" | "alpha") {
+            return 1.0;
+        }
+        match self {
+            Scalar::I8 | Scalar::U8 => 1.0 / u8::MAX as f64,
+            Scalar::I16 | Scalar::U16 => 1.0 / u16::MAX as f64,
+            _ => 1.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PropertyKind {
+    Scalar(Scalar),
+    List { count: Scalar, item: Scalar },
+}
+
+#[derive(Clone, Debug)]
+struct Property {
+    name: String,
+    kind: PropertyKind,
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+impl Element {
+    /// Find the first property called one of `names`
+    fn position(&self, names: &[&str]) -> Option<usize> {
+        names
+            .iter()
+            .find_map(|name| self.properties.iter().position(|p| p.name == *name))
+    }
+}
+
+fn read_header(reader: &mut impl BufRead) -> Result<(PlyFormat, Vec<Element>), MeshIoError> {
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+    let mut line = String::new();
+    for number in 1.. {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(MeshIoError::parse(number, "missing end_header"));
+        }
+        let tokens: Vec<_> = line.split_whitespace().collect();
+        let scalar = |name: &str| {
+            Scalar::parse(name)
+                .ok_or_else(|| MeshIoError::parse(number, format!("unknown type {name}")))
+        };
+
+        match tokens[..] {
+            ["ply"] if number == 1 => {}
+            _ if number == 1 => return Err(MeshIoError::parse(number, "not a PLY file")),
+            ["format", name, _] => {
+                format = Some(match name {
+                    "ascii" => PlyFormat::Ascii,
+                    "binary_little_endian" => PlyFormat::BinaryLittleEndian,
+                    "binary_big_endian" => PlyFormat::BinaryBigEndian,
+                    _ => return Err(MeshIoError::parse(number, format!("unknown format {name}"))),
+                });
+            }
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count
+                    .parse()
+                    .map_err(|_| MeshIoError::parse(number, format!("invalid count {count}")))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count, item, name] => {
+                let kind = PropertyKind::List {
+                    count: scalar(count)?,
+                    item: scalar(item)?,
+                };
+                push_property(&mut elements, name, kind, number)?;
+            }
+            ["property", ty, name] => {
+                push_property(
+                    &mut elements,
+                    name,
+                    PropertyKind::Scalar(scalar(ty)?),
+                    number,
+                )?;
+            }
+            ["end_header"] => break,
+            [] | ["comment", ..] | ["obj_info", ..] => {}
+            _ => return Err(MeshIoError::parse(number, "invalid header line")),
+        }
+    }
+
+    let format = format.ok_or_else(|| MeshIoError::Malformed("PLY header has no format".into()))?;
+    Ok((format, elements))
+}
+
+fn push_property(
+    elements: &mut [Element],
+    name: &str,
+    kind: PropertyKind,
+    line: usize,
+) -> Result<(), MeshIoError> {
+    let element = elements
+        .last_mut()
+        .ok_or_else(|| MeshIoError::parse(line, "property before element"))?;
+    element.properties.push(Property {
+        name: name.to_string(),
+        kind,
+    });
+    Ok(())
+}
+
+/// The values following the header
+enum Body<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary { bytes: &'a [u8], big_endian: bool },
+}
+
+impl Body<'_> {
+    fn next(&mut self, scalar: Scalar) -> Result<f64, MeshIoError> {
+        let truncated = || MeshIoError::Malformed("PLY body ends early".into());
+        match self {
+            Body::Ascii(tokens) => {
+                let token = tokens.next().ok_or_else(truncated)?;
+                token
+                    .parse()
+                    .map_err(|_| MeshIoError::Malformed(format!("invalid PLY value {token}")))
+            }
+            Body::Binary { bytes, big_endian } => {
+                let size = scalar.size();
+                if bytes.len() < size {
+                    return Err(truncated());
+                }
+                let (value, rest) = bytes.split_at(size);
+                *bytes = rest;
+
+                let mut buffer = [0; 8];
+                buffer[..size].copy_from_slice(value);
+                if *big_endian {
+                    buffer[..size].reverse();
+                }
+                let [b0, b1, b2, b3, ..] = buffer;
+                Ok(match scalar {
+                    Scalar::I8 => b0 as i8 as f64,
+                    Scalar::U8 => b0 as f64,
+                    Scalar::I16 => i16::from_le_bytes([b0, b1]) as f64,
+                    Scalar::U16 => u16::from_le_bytes([b0, b1]) as f64,
+                    Scalar::I32 => i32::from_le_bytes([b0, b1, b2, b3]) as f64,
+                    Scalar::U32 => u32::from_le_bytes([b0, b1, b2, b3]) as f64,
+                    Scalar::F32 => f32::from_le_bytes([b0, b1, b2, b3]) as f64,
+                    Scalar::F64 => f64::from_le_bytes(buffer),
+                })
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Value {
+    U8(u8),
+    U32(u32),
+    F32(f32),
+}
+
+fn write_row(writer: &mut impl Write, format: PlyFormat, row: &[Value]) -> Result<(), MeshIoError> {
+    match format {
+        PlyFormat::Ascii => {
+            let tokens: Vec<_> = row
+                .iter()
+                .map(|value| match value {
+                    Value::U8(value) => value.to_string(),
+                    Value::U32(value) => value.to_string(),
+                    Value::F32(value) => value.to_string(),
+                })
+                .collect();
+            writeln!(writer, "{}", tokens.join(" "))?;
+        }
+        PlyFormat::BinaryLittleEndian => {
+            for value in row {
+                match value {
+                    Value::U8(value) => writer.write_all(&[*value])?,
+                    Value::U32(value) => writer.write_all(&value.to_le_bytes())?,
+                    Value::F32(value) => writer.write_all(&value.to_le_bytes())?,
+                }
+            }
+        }
+        PlyFormat::BinaryBigEndian => {
+            for value in row {
+                match value {
+                    Value::U8(value) => writer.write_all(&[*value])?,
+                    Value::U32(value) => writer.write_all(&value.to_be_bytes())?,
+                    Value::F32(value) => writer.write_all(&value.to_be_bytes())?,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn zip3(x: Vec<f32>, y: Vec<f32>, z: Vec<f32>) -> Vec<[f32; 3]> {
+    x.into_iter()
+        .zip(y)
+        .zip(z)
+        .map(|((x, y), z)| [x, y, z])
+        .collect()
+}
@@ -0,0 +1,173 @@
+use super::MeshIoError;
+use crate::mesh::Mesh;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+
+/// How an STL file is encoded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StlFormat {
+    Ascii,
+    #[default]
+    Binary,
+}
+
+/// Read an STL file, see [`read_stl`]
+pub fn load_stl(path: impl AsRef<Path>, weld: Option<f32>) -> Result<Mesh, MeshIoError> {
+    read_stl(BufReader::new(File::open(path)?), weld)
+}
+
+/// Read an ASCII or binary STL file into a `TriangleList`.
+///
+/// Without `weld` every triangle has its own three vertices with the facet normal. With a weld
+/// tolerance, vertices closer than it are merged with [`Mesh::weld_vertices`] into an indexed
+/// mesh, and the facet normals are dropped because shared vertices can't keep them.
+pub fn read_stl(mut reader: impl Read, weld: Option<f32>) -> Result<Mesh, MeshIoError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let binary_size = bytes
+        .get(80..84)
+        .map(|count| 84 + 50 * u32::from_le_bytes(count.try_into().unwrap()) as usize);
+    let (positions, normals) = if binary_size == Some(bytes.len()) {
+        read_binary(&bytes)
+    } else if bytes.starts_with(b"solid") {
+        read_ascii(&bytes)?
+    } else {
+        return Err(MeshIoError::Malformed(
+            "STL file is neither ASCII nor the size its triangle count says".into(),
+        ));
+    };
+
+    let mut mesh = Mesh::new(wgpu::PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    match weld {
+        Some(tolerance) => mesh.weld_vertices(tolerance)?,
+        None => mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals),
+    }
+    Ok(mesh)
+}
+
+/// Write the triangles of `mesh` as an STL file.
+///
+/// Facet normals are computed from the positions, other attributes are lost.
+pub fn write_stl(
+    mut writer: impl Write,
+    mesh: &Mesh,
+    format: StlFormat,
+) -> Result<(), MeshIoError> {
+    let positions = mesh.positions()?;
+    let triangles = mesh.triangles()?;
+    let facets = triangles.iter().map(|triangle| {
+        let [a, b, c] = triangle.map(|vertex| positions[vertex as usize]);
+        let [va, vb, vc] = [a, b, c].map(Vec3::from);
+        (
+            (vb - va).cross(vc - va).normalize_or_zero().to_array(),
+            [a, b, c],
+        )
+    });
+
+    match format {
+        StlFormat::Ascii => {
+            writeln!(writer, "solid amel")?;
+            for ([nx, ny, nz], vertices) in facets {
+                writeln!(writer, "facet normal {nx} {ny} {nz}\nouter loop")?;
+                for [x, y, z] in vertices {
+                    writeln!(writer, "vertex {x} {y} {z}")?;
+                }
+                writeln!(writer, "endloop\nendfacet")?;
+            }
+            writeln!(writer, "endsolid amel")?;
+        }
+        StlFormat::Binary => {
+            let count = u32::try_from(triangles.len()).map_err(|_| {
+                MeshIoError::Malformed("binary STL can't hold more than u32::MAX triangles".into())
+            })?;
+            writer.write_all(&[0; 80])?;
+            writer.write_all(&count.to_le_bytes())?;
+            for (normal, vertices) in facets {
+                for value in std::iter::once(normal).chain(vertices).flatten() {
+                    writer.write_all(&value.to_le_bytes())?;
+                }
+                writer.write_all(&[0; 2])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+type Facets = (Vec<[f32; 3]>, Vec<[f32; 3]>);
+
+/// Read the corners and per corner facet normals of a binary STL
+fn read_binary(bytes: &[u8]) -> Facets {
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    for facet in bytes[84..].chunks_exact(50) {
+        let vector = |offset: usize| -> [f32; 3] {
+            std::array::from_fn(|i| {
+                let start = offset + i * 4;
+                f32::from_le_bytes(facet[start..start + 4].try_into().unwrap())
+            })
+        };
+        let corners = [vector(12), vector(24), vector(36)];
+        let normal = facet_normal(vector(0), corners);
+        positions.extend(corners);
+        normals.extend([normal; 3]);
+    }
+    (positions, normals)
+}
+
+/// Read the corners and per corner facet normals of an ASCII STL
+fn read_ascii(bytes: &[u8]) -> Result<Facets, MeshIoError> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|_| MeshIoError::Malformed("ASCII STL is not valid text".into()))?;
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut normal = [0.0; 3];
+    let mut corners = Vec::with_capacity(3);
+
+    for (number, line) in text.lines().enumerate() {
+        let number = number + 1;
+        let mut tokens = line.split_whitespace();
+        let vector = |tokens: std::str::SplitWhitespace| -> Result<[f32; 3], MeshIoError> {
+            let values = tokens
+                .map(|token| token.parse::<f32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| MeshIoError::parse(number, "invalid number"))?;
+            <[f32; 3]>::try_from(values)
+                .map_err(|_| MeshIoError::parse(number, "expected 3 coordinates"))
+        };
+
+        match tokens.next() {
+            Some("facet") => {
+                if tokens.next() != Some("normal") {
+                    return Err(MeshIoError::parse(number, "expected facet normal"));
+                }
+                normal = vector(tokens)?;
+                corners.clear();
+            }
+            Some("vertex") => corners.push(vector(tokens)?),
+            Some("endfacet") => {
+                let [a, b, c] = corners[..] else {
+                    return Err(MeshIoError::parse(number, "facets need 3 vertices"));
+                };
+                positions.extend([a, b, c]);
+                normals.extend([facet_normal(normal, [a, b, c]); 3]);
+            }
+            _ => {}
+        }
+    }
+    Ok((positions, normals))
+}
+
+/// Use the stored normal of a facet, or compute it when the file left it zeroed
+fn facet_normal(normal: [f32; 3], [a, b, c]: [[f32; 3]; 3]) -> [f32; 3] {
+    if normal != [0.0; 3] {
+        return normal;
+    }
+    let [a, b, c] = [a, b, c].map(Vec3::from);
+    (b - a).cross(c - a).normalize_or_zero().to_array()
+}
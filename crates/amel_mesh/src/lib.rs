@@ -2,16 +2,34 @@ pub mod attribute;
 pub mod conversion;
 pub mod gpu_mesh;
 pub mod indices;
+pub mod io;
+pub mod merge;
 pub mod mesh;
+pub mod normals;
+pub mod optimize;
 pub mod primitive;
+pub mod simplify;
+pub mod subdivide;
+pub mod tangents;
+pub mod topology;
+pub mod transform;
+pub mod validation;
+pub mod weld;
+pub mod wireframe;
 
 pub mod prelude {
     pub use super::attribute::*;
     pub use super::conversion::*;
     pub use super::gpu_mesh::*;
     pub use super::indices::*;
+    pub use super::io::*;
     pub use super::mesh::*;
+    pub use super::normals::*;
+    pub use super::optimize::*;
     pub use super::primitive::*;
+    pub use super::simplify::*;
+    pub use super::subdivide::*;
+    pub use super::validation::*;
     // pub use crate::Mesh;
 }
 
@@ -0,0 +1,151 @@
+use super::attribute::VertexAttributeValues;
+use super::indices::Indices;
+use super::mesh::Mesh;
+use super::validation::MeshError;
+
+use amel_math::prelude::*;
+
+impl Mesh {
+    /// Combine `parts` into a single mesh, baking every transform into its part.
+    ///
+    /// See [`Mesh::append`] for how the attributes and indices are combined.
+    pub fn merge(parts: &[(Mesh, Mat4)]) -> Result<Mesh, MeshError> {
+        let Some((first, _)) = parts.first() else {
+            return Ok(Mesh::default());
+        };
+
+        let mut merged = Mesh::new(first.primitive_topology);
+        for (mesh, transform) in parts {
+            merged.append(mesh, *transform)?;
+        }
+        Ok(merged)
+    }
+
+    /// Append `other` transformed by `transform` to this mesh.
+    ///
+    /// Attributes that only one of the meshes has are filled with zeroes for the other one,
+    /// except for colors, which default to white. Indices are rebased and promoted to
+    /// [`Indices::U32`] when 16 bits are no longer enough, and strips are joined with a
+    /// primitive restart. Nothing is changed when an error is returned.
+    pub fn append(&mut self, other: &Mesh, transform: Mat4) -> Result<(), MeshError> {
+        if self.primitive_topology != other.primitive_topology {
+            return Err(MeshError::IncompatibleTopology {
+                a: self.primitive_topology,
+                b: other.primitive_topology,
+            });
+        }
+        for (&location, values) in &other.attributes {
+            if let Some(existing) = self.attribute(location) {
+                let (expected, found) = (existing.into(), values.into());
+                if expected != found {
+                    return Err(MeshError::InvalidAttributeFormat {
+                        location,
+                        expected,
+                        found,
+                    });
+                }
+            }
+        }
+
+        let offset = self.count_vertices();
+        let other_count = other.count_vertices();
+        let mut other = other.clone();
+        other.transform(&transform);
+
+        for (location, values) in self.attributes.iter_mut() {
+            if !other.attributes.contains_key(location) {
+                values.extend(&default_values(*location, values, other_count));
+            }
+        }
+        for (location, values) in other.attributes {
+            match self.attributes.get_mut(&location) {
+                Some(existing) => {
+                    existing.extend(&values);
+                }
+                None => {
+                    let mut existing = default_values(location, &values, offset);
+                    existing.extend(&values);
+                    self.attributes.insert(location, existing);
+                }
+            }
+        }
+
+        let strip = self.primitive_topology.is_strip();
+        if self.indices.is_none() && other.indices.is_none() && !strip {
+            return Ok(());
+        }
+
+        let use_u16 = !matches!(self.indices, Some(Indices::U32(_)))
+            && !matches!(other.indices, Some(Indices::U32(_)))
+            && (matches!(self.indices, Some(Indices::U16(_)))
+                || matches!(other.indices, Some(Indices::U16(_))))
+            && offset + other_count <= u16::MAX as usize + !strip as usize;
+
+        let mut indices = widen_indices(self.indices.as_ref(), offset, strip);
+        let other_indices = widen_indices(other.indices.as_ref(), other_count, strip);
+        if strip && !indices.is_empty() && !other_indices.is_empty() {
+            indices.push(u32::MAX);
+        }
+        indices.extend(other_indices.into_iter().map(|index| {
+            if strip && index == u32::MAX {
+                index
+            } else {
+                index + offset as u32
+            }
+        }));
+
+        self.indices = Some(if use_u16 {
+            Indices::U16(
+                indices
+                    .into_iter()
+                    .map(|index| {
+                        if index == u32::MAX {
+                            u16::MAX
+                        } else {
+                            index as u16
+                        }
+                    })
+                    .collect(),
+            )
+        } else {
+            Indices::U32(indices)
+        });
+        Ok(())
+    }
+}
+
+/// Get the indices as `u32`, with strip restarts mapped to `u32::MAX`, or a sequential
+/// list covering `vertex_count` vertices for non-indexed meshes.
+fn widen_indices(indices: Option<&Indices>, vertex_count: usize, strip: bool) -> Vec<u32> {
+    match indices {
+        Some(Indices::U16(indices)) => indices
+            .iter()
+            .map(|&index| {
+                if strip && index == u16::MAX {
+                    u32::MAX
+                } else {
+                    index as u32
+                }
+            })
+            .collect(),
+        Some(Indices::U32(indices)) => indices.clone(),
+        None => (0..vertex_count as u32).collect(),
+    }
+}
+
+/// Get `len` placeholder values for the attribute at `location`, in the format of `like`
+fn default_values(
+    location: u32,
+    like: &VertexAttributeValues,
+    len: usize,
+) -> VertexAttributeValues {
+    match like {
+        VertexAttributeValues::Float32x4(_) if location == Mesh::ATTRIBUTE_COLOR => {
+            VertexAttributeValues::Float32x4(vec![[1.0; 4]; len])
+        }
+        VertexAttributeValues::Unorm8x4(_) if location == Mesh::ATTRIBUTE_COLOR => {
+            VertexAttributeValues::Unorm8x4(vec![[u8::MAX; 4]; len])
+        }
+        _ => like.zeroed(len),
+    }
+}
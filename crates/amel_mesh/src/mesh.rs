@@ -22,6 +22,8 @@ impl Mesh {
     pub const ATTRIBUTE_UV_1: u32 = 3;
     pub const ATTRIBUTE_TANGENT: u32 = 4;
     pub const ATTRIBUTE_COLOR: u32 = 5;
+    pub const ATTRIBUTE_JOINT_INDEX: u32 = 6;
+    pub const ATTRIBUTE_JOINT_WEIGHT: u32 = 7;
 
     pub fn new(primitive_topology: wgpu::PrimitiveTopology) -> Self {
         Self {
@@ -83,6 +85,29 @@ impl Mesh {
     pub fn attribute_mut(&mut self, location: u32) -> Option<&mut VertexAttributeValues> {
         self.attributes.get_mut(&location)
     }
+
+    /// Get the number of vertices, taken from the positions if the mesh has any
+    pub fn count_vertices(&self) -> usize {
+        self.attribute(Mesh::ATTRIBUTE_POSITION)
+            .or_else(|| self.attributes.values().next())
+            .map_or(0, VertexAttributeValues::len)
+    }
+}
+
+/// Assign every vertex the id of the group of vertices sharing its exact position, returning
+/// the ids and the number of groups
+pub(crate) fn position_groups(positions: &[[f32; 3]]) -> (Vec<usize>, usize) {
+    let mut ids = HashMap::new();
+    let groups = positions
+        .iter()
+        .map(|position| {
+            let next = ids.len();
+            // Adding zero turns -0.0 into 0.0 so both land in the same group
+            *ids.entry(position.map(|x| (x + 0.0).to_bits()))
+                .or_insert(next)
+        })
+        .collect();
+    (groups, ids.len())
 }
 
 // struct VertexAttribute {
@@ -0,0 +1,185 @@
+use super::mesh::{position_groups, Mesh};
+use super::validation::MeshError;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+use std::collections::HashMap;
+
+/// How the faces around a vertex contribute to its smooth normal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NormalWeighting {
+    /// Weight every face by its area, so that large faces dominate.
+    #[default]
+    Area,
+    /// Weight every face by its angle at the vertex, which is independent of how the
+    /// surrounding faces happen to be triangulated.
+    Angle,
+}
+
+impl Mesh {
+    /// Compute a normal per face and write it to [`Mesh::ATTRIBUTE_NORMAL`].
+    ///
+    /// Faces can't share vertices with flat shading, so every triangle gets its own three
+    /// vertices and the mesh becomes a non-indexed `TriangleList`.
+    pub fn compute_flat_normals(&mut self) -> Result<(), MeshError> {
+        let triangles = self.triangles()?;
+        self.positions()?;
+
+        let corners: Vec<u32> = triangles.into_iter().flatten().collect();
+        for values in self.attributes.values_mut() {
+            *values = values.gather(&corners);
+        }
+        self.primitive_topology = wgpu::PrimitiveTopology::TriangleList;
+        self.indices = None;
+
+        let normals: Vec<[f32; 3]> = self
+            .positions()?
+            .chunks_exact(3)
+            .flat_map(|triangle| {
+                let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(Vec3::from);
+                [(b - a).cross(c - a).normalize_or_zero().into(); 3]
+            })
+            .collect();
+        self.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        Ok(())
+    }
+
+    /// Compute smooth normals and write them to [`Mesh::ATTRIBUTE_NORMAL`].
+    ///
+    /// Vertices at the same position share their normal, so UV seams don't show up as
+    /// shading seams.
+    pub fn compute_smooth_normals(&mut self, weighting: NormalWeighting) -> Result<(), MeshError> {
+        let triangles = self.triangles()?;
+        let positions = self.positions()?;
+        let (groups, group_count) = position_groups(positions);
+
+        let mut sums = vec![Vec3::ZERO; group_count];
+        for triangle in &triangles {
+            let weighted = weighted_normals(positions, *triangle, weighting);
+            for (&vertex, normal) in triangle.iter().zip(weighted) {
+                sums[groups[vertex as usize]] += normal;
+            }
+        }
+
+        let normals: Vec<[f32; 3]> = groups
+            .iter()
+            .map(|&group| sums[group].normalize_or_zero().into())
+            .collect();
+        self.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        Ok(())
+    }
+
+    /// Compute smooth normals that keep edges sharper than `crease_angle` radians, and write
+    /// them to [`Mesh::ATTRIBUTE_NORMAL`].
+    ///
+    /// Each face corner averages the faces around it that are within `crease_angle` of its
+    /// own face. Vertices that end up with several normals are split, which turns strips into
+    /// an indexed `TriangleList`.
+    pub fn compute_creased_normals(
+        &mut self,
+        weighting: NormalWeighting,
+        crease_angle: f32,
+    ) -> Result<(), MeshError> {
+        let triangles = self.triangles()?;
+        let positions = self.positions()?;
+        let (groups, group_count) = position_groups(positions);
+
+        let face_normals: Vec<Vec3> = triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let [a, b, c] = [a, b, c].map(|i| Vec3::from(positions[i as usize]));
+                (b - a).cross(c - a).normalize_or_zero()
+            })
+            .collect();
+        let weighted: Vec<[Vec3; 3]> = triangles
+            .iter()
+            .map(|&triangle| weighted_normals(positions, triangle, weighting))
+            .collect();
+
+        // Every face touching a position, with the corner it touches it with
+        let mut group_faces = vec![Vec::new(); group_count];
+        for (face, triangle) in triangles.iter().enumerate() {
+            for (corner, &vertex) in triangle.iter().enumerate() {
+                group_faces[groups[vertex as usize]].push((face, corner));
+            }
+        }
+
+        let min_cos = crease_angle.cos();
+        let mut vertex_count = positions.len();
+        let mut normals = vec![[0.0; 3]; vertex_count];
+        let mut assigned = vec![false; vertex_count];
+        let mut splits: HashMap<(u32, [u32; 3]), u32> = HashMap::new();
+        let mut sources = Vec::new();
+        let mut indices = Vec::with_capacity(triangles.len() * 3);
+
+        for (face, triangle) in triangles.iter().enumerate() {
+            for &vertex in triangle {
+                let normal: Vec3 = group_faces[groups[vertex as usize]]
+                    .iter()
+                    .filter(|&&(other, _)| face_normals[face].dot(face_normals[other]) >= min_cos)
+                    .map(|&(other, corner)| weighted[other][corner])
+                    .sum();
+                let normal: [f32; 3] = normal.normalize_or_zero().into();
+
+                let index = if !assigned[vertex as usize] {
+                    assigned[vertex as usize] = true;
+                    normals[vertex as usize] = normal;
+                    vertex
+                } else if normals[vertex as usize] == normal {
+                    vertex
+                } else {
+                    *splits
+                        .entry((vertex, normal.map(f32::to_bits)))
+                        .or_insert_with(|| {
+                            sources.push(vertex);
+                            normals.push(normal);
+                            vertex_count += 1;
+                            vertex_count as u32 - 1
+                        })
+                };
+                indices.push(index);
+            }
+        }
+
+        if !sources.is_empty() || self.primitive_topology.is_strip() {
+            let mut order: Vec<u32> = (0..positions.len() as u32).collect();
+            order.extend(sources);
+            for values in self.attributes.values_mut() {
+                *values = values.gather(&order);
+            }
+            self.primitive_topology = wgpu::PrimitiveTopology::TriangleList;
+            self.insert_indices(indices);
+        }
+        self.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        Ok(())
+    }
+}
+
+/// Get the contribution of a triangle to the normal at each of its corners
+fn weighted_normals(
+    positions: &[[f32; 3]],
+    triangle: [u32; 3],
+    weighting: NormalWeighting,
+) -> [Vec3; 3] {
+    let [a, b, c] = triangle.map(|i| Vec3::from(positions[i as usize]));
+    let normal = (b - a).cross(c - a);
+    match weighting {
+        NormalWeighting::Area => [normal; 3],
+        NormalWeighting::Angle => {
+            let normal = normal.normalize_or_zero();
+            let angle = |corner: Vec3, u: Vec3, v: Vec3| {
+                let (u, v) = (u - corner, v - corner);
+                if u.length_squared() > 0.0 && v.length_squared() > 0.0 {
+                    u.angle_between(v)
+                } else {
+                    0.0
+                }
+            };
+            [
+                normal * angle(a, b, c),
+                normal * angle(b, c, a),
+                normal * angle(c, a, b),
+            ]
+        }
+    }
+}
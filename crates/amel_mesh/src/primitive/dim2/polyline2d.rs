@@ -1,3 +1,4 @@
+use crate::indices::Indices;
 use crate::mesh::Mesh;
 use crate::primitive::Meshable;
 
@@ -25,37 +26,55 @@ impl<const N: usize> Polyline2dMeshBuilder<N> {
     }
 
     pub fn build(&self) -> Mesh {
-        let positions: Vec<[f32; 3]> = self
-            .polyline
-            .vertices
-            .iter()
-            .map(|v| [v.x, v.y, 0.0])
-            .collect();
-        let normals = vec![[0.0, 0.0, 1.0]; N];
-        let uvs = vec![[0.0, 0.0]; N];
-
-        let mut indices: Vec<u16> =
-            Vec::with_capacity(if self.closed { (N * 3) + 1 } else { N * 3 });
-
-        for i in 0..N {
-            indices.push(i as u16);
-            if self.closed && i == N - 1 {
-                indices.push(0);
-            }
-        }
+        build_polyline2d(&self.polyline.vertices, self.closed)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BoxedPolyline2dMeshBuilder {
+    pub polyline: BoxedPolyline2d,
+    pub closed: bool,
+}
 
-        if !self.closed {
-            indices.pop();
+impl BoxedPolyline2dMeshBuilder {
+    #[inline]
+    pub fn new(vertices: impl IntoIterator<Item = Vec2>) -> Self {
+        Self {
+            polyline: BoxedPolyline2d::from_iter(vertices),
+            closed: false,
         }
+    }
 
-        Mesh::new(wgpu::PrimitiveTopology::LineStrip)
-            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
-            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
-            .with_inserted_indices(indices)
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        build_polyline2d(&self.polyline.vertices, self.closed)
     }
 }
 
+fn build_polyline2d(vertices: &[Vec2], closed: bool) -> Mesh {
+    let count = vertices.len();
+    let positions: Vec<[f32; 3]> = vertices.iter().map(|v| [v.x, v.y, 0.0]).collect();
+    let normals = vec![[0.0, 0.0, 1.0]; count];
+    let uvs = vec![[0.0, 0.0]; count];
+
+    // Fixed size polylines keep their 16 bit indices, only long boxed ones need 32 bits
+    let mut indices: Vec<u32> = (0..count as u32).collect();
+    // Closing one or two points would only draw over the line again
+    if closed && count > 2 {
+        indices.push(0);
+    }
+
+    Mesh::new(wgpu::PrimitiveTopology::LineStrip)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::fit(indices, count))
+}
+
 impl<const N: usize> Meshable for Polyline2d<N> {
     type Output = Polyline2dMeshBuilder<N>;
 
@@ -78,3 +97,26 @@ impl<const N: usize> From<Polyline2dMeshBuilder<N>> for Mesh {
         polyline.build()
     }
 }
+
+impl Meshable for BoxedPolyline2d {
+    type Output = BoxedPolyline2dMeshBuilder;
+
+    fn to_mesh_builder(&self) -> Self::Output {
+        BoxedPolyline2dMeshBuilder {
+            polyline: self.clone(),
+            closed: false,
+        }
+    }
+}
+
+impl From<BoxedPolyline2d> for Mesh {
+    fn from(polyline: BoxedPolyline2d) -> Self {
+        polyline.to_mesh_builder().build()
+    }
+}
+
+impl From<BoxedPolyline2dMeshBuilder> for Mesh {
+    fn from(polyline: BoxedPolyline2dMeshBuilder) -> Self {
+        polyline.build()
+    }
+}
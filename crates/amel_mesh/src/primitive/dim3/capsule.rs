@@ -0,0 +1,169 @@
+use crate::mesh::Mesh;
+use crate::primitive::Meshable;
+
+use super::push_grid_indices;
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+
+/// A builder used for creating a [`Mesh`] with a [`Capsule3d`] shape.
+#[derive(Clone, Copy, Debug)]
+pub struct CapsuleMeshBuilder {
+    /// The [`Capsule3d`] shape.
+    pub capsule: Capsule3d,
+    /// The number of vertices around each ring of the capsule.
+    #[doc(alias = "longitudes")]
+    pub resolution: usize,
+    /// The number of rings in each hemisphere.
+    #[doc(alias = "latitudes")]
+    pub rings: usize,
+    /// The number of segments along the cylindrical part of the capsule.
+    pub segments: usize,
+    pub wireframe: bool,
+}
+
+impl Default for CapsuleMeshBuilder {
+    fn default() -> Self {
+        Self {
+            capsule: Capsule3d::default(),
+            resolution: 32,
+            rings: 8,
+            segments: 1,
+            wireframe: false,
+        }
+    }
+}
+
+impl CapsuleMeshBuilder {
+    /// Creates a new [`CapsuleMeshBuilder`] from a radius, the length of the cylindrical part
+    /// and a resolution.
+    #[inline]
+    pub fn new(radius: f32, length: f32, resolution: usize) -> Self {
+        Self {
+            capsule: Capsule3d::new(radius, length),
+            resolution,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub const fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    #[inline]
+    pub const fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings;
+        self
+    }
+
+    #[inline]
+    pub const fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    #[inline]
+    pub const fn wireframe(mut self, wireframe: bool) -> Self {
+        self.wireframe = wireframe;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        assert!(
+            self.resolution > 2,
+            "capsule must have a resolution of at least 3"
+        );
+        assert!(self.rings > 0, "capsule must have at least 1 ring");
+        assert!(self.segments > 0, "capsule must have at least 1 segment");
+
+        let Capsule3d {
+            radius,
+            half_length,
+        } = self.capsule;
+
+        // Each row is a polar angle from the top and the height of the center it is measured from
+        let mut rows: Vec<(f32, f32)> = Vec::new();
+        for ring in 0..=self.rings {
+            rows.push((ring as f32 / self.rings as f32 * FRAC_PI_2, half_length));
+        }
+        for segment in 1..self.segments {
+            let t = segment as f32 / self.segments as f32;
+            rows.push((FRAC_PI_2, half_length - 2.0 * half_length * t));
+        }
+        for ring in 0..=self.rings {
+            rows.push((
+                FRAC_PI_2 + ring as f32 / self.rings as f32 * FRAC_PI_2,
+                -half_length,
+            ));
+        }
+
+        // Spread the V coordinate by arc length so that textures aren't stretched
+        let total_length = PI * radius + 2.0 * half_length;
+        let columns = self.resolution + 1;
+        let step = TAU / self.resolution as f32;
+
+        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(rows.len() * columns);
+        let mut normals: Vec<[f32; 3]> = Vec::with_capacity(rows.len() * columns);
+        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(rows.len() * columns);
+        let mut indices: Vec<u32> = Vec::new();
+
+        for &(polar, center) in &rows {
+            let (ring_radius, y) = (polar.sin(), polar.cos());
+            let arc = polar.min(FRAC_PI_2) * radius
+                + (half_length - center)
+                + (polar - FRAC_PI_2).max(0.0) * radius;
+            let v = arc / total_length;
+            for column in 0..columns {
+                let u = column as f32 / self.resolution as f32;
+                let (sin, cos) = (column as f32 * step).sin_cos();
+                let normal = Vec3::new(cos * ring_radius, y, -sin * ring_radius);
+                positions.push((normal * radius + Vec3::Y * center).into());
+                normals.push(normal.into());
+                uvs.push([u, v]);
+            }
+        }
+        push_grid_indices(
+            &mut indices,
+            0,
+            rows.len(),
+            columns,
+            self.wireframe,
+            (true, true),
+        );
+
+        let primitive_topology = if self.wireframe {
+            wgpu::PrimitiveTopology::LineList
+        } else {
+            wgpu::PrimitiveTopology::TriangleList
+        };
+
+        Mesh::new(primitive_topology)
+            .with_inserted_indices(indices)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    }
+}
+
+impl Meshable for Capsule3d {
+    type Output = CapsuleMeshBuilder;
+    fn to_mesh_builder(&self) -> Self::Output {
+        CapsuleMeshBuilder {
+            capsule: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Capsule3d> for Mesh {
+    fn from(capsule: Capsule3d) -> Self {
+        capsule.to_mesh_builder().build()
+    }
+}
+
+impl From<CapsuleMeshBuilder> for Mesh {
+    fn from(capsule: CapsuleMeshBuilder) -> Self {
+        capsule.build()
+    }
+}
@@ -0,0 +1,159 @@
+use crate::mesh::Mesh;
+use crate::primitive::Meshable;
+
+use super::{push_cap_indices, push_grid_indices};
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+
+/// A builder used for creating a [`Mesh`] with a [`Cone`] shape.
+#[derive(Clone, Copy, Debug)]
+pub struct ConeMeshBuilder {
+    /// The [`Cone`] shape.
+    pub cone: Cone,
+    /// The number of vertices around the base of the cone.
+    pub resolution: usize,
+    /// The number of segments from the tip to the base of the cone.
+    pub segments: usize,
+    /// Whether the base is closed with a cap.
+    pub caps: bool,
+    pub wireframe: bool,
+}
+
+impl Default for ConeMeshBuilder {
+    fn default() -> Self {
+        Self {
+            cone: Cone::default(),
+            resolution: 32,
+            segments: 1,
+            caps: true,
+            wireframe: false,
+        }
+    }
+}
+
+impl ConeMeshBuilder {
+    /// Creates a new [`ConeMeshBuilder`] from a base radius, a height and a resolution.
+    #[inline]
+    pub fn new(radius: f32, height: f32, resolution: usize) -> Self {
+        Self {
+            cone: Cone::new(radius, height),
+            resolution,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub const fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    #[inline]
+    pub const fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    #[inline]
+    pub const fn caps(mut self, caps: bool) -> Self {
+        self.caps = caps;
+        self
+    }
+
+    #[inline]
+    pub const fn wireframe(mut self, wireframe: bool) -> Self {
+        self.wireframe = wireframe;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        assert!(
+            self.resolution > 2,
+            "cone must have a resolution of at least 3"
+        );
+        assert!(self.segments > 0, "cone must have at least 1 segment");
+
+        let Cone { radius, height } = self.cone;
+        let half_height = height / 2.0;
+        let columns = self.resolution + 1;
+        let rows = self.segments + 1;
+        let step = TAU / self.resolution as f32;
+
+        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(rows * columns);
+        let mut normals: Vec<[f32; 3]> = Vec::with_capacity(rows * columns);
+        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(rows * columns);
+        let mut indices: Vec<u32> = Vec::new();
+
+        // The side, row by row from the tip, which is repeated for every column
+        // so that each column gets its own normal
+        for row in 0..rows {
+            let v = row as f32 / self.segments as f32;
+            let y = half_height - height * v;
+            for column in 0..columns {
+                let u = column as f32 / self.resolution as f32;
+                let (sin, cos) = (column as f32 * step).sin_cos();
+                let normal = Vec3::new(cos * height, radius, -sin * height).normalize();
+                positions.push([cos * radius * v, y, -sin * radius * v]);
+                normals.push(normal.into());
+                uvs.push([u, v]);
+            }
+        }
+        push_grid_indices(
+            &mut indices,
+            0,
+            rows,
+            columns,
+            self.wireframe,
+            (true, false),
+        );
+
+        if self.caps && !self.wireframe {
+            let center = positions.len() as u32;
+            positions.push([0.0, -half_height, 0.0]);
+            normals.push([0.0, -1.0, 0.0]);
+            uvs.push([0.5, 0.5]);
+
+            for column in 0..self.resolution {
+                let (sin, cos) = (column as f32 * step).sin_cos();
+                positions.push([cos * radius, -half_height, -sin * radius]);
+                normals.push([0.0, -1.0, 0.0]);
+                uvs.push([0.5 + 0.5 * cos, 0.5 + 0.5 * sin]);
+            }
+            push_cap_indices(&mut indices, center, self.resolution, false);
+        }
+
+        let primitive_topology = if self.wireframe {
+            wgpu::PrimitiveTopology::LineList
+        } else {
+            wgpu::PrimitiveTopology::TriangleList
+        };
+
+        Mesh::new(primitive_topology)
+            .with_inserted_indices(indices)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    }
+}
+
+impl Meshable for Cone {
+    type Output = ConeMeshBuilder;
+    fn to_mesh_builder(&self) -> Self::Output {
+        ConeMeshBuilder {
+            cone: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Cone> for Mesh {
+    fn from(cone: Cone) -> Self {
+        cone.to_mesh_builder().build()
+    }
+}
+
+impl From<ConeMeshBuilder> for Mesh {
+    fn from(cone: ConeMeshBuilder) -> Self {
+        cone.build()
+    }
+}
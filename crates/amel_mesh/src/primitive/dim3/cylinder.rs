@@ -1 +1,161 @@
+use crate::mesh::Mesh;
+use crate::primitive::Meshable;
 
+use super::{push_cap_indices, push_grid_indices};
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+
+/// A builder used for creating a [`Mesh`] with a [`Cylinder`] shape.
+#[derive(Clone, Copy, Debug)]
+pub struct CylinderMeshBuilder {
+    /// The [`Cylinder`] shape.
+    pub cylinder: Cylinder,
+    /// The number of vertices around each ring of the cylinder.
+    pub resolution: usize,
+    /// The number of segments along the height of the cylinder.
+    pub segments: usize,
+    /// Whether the top and bottom are closed with caps.
+    pub caps: bool,
+    pub wireframe: bool,
+}
+
+impl Default for CylinderMeshBuilder {
+    fn default() -> Self {
+        Self {
+            cylinder: Cylinder::default(),
+            resolution: 32,
+            segments: 1,
+            caps: true,
+            wireframe: false,
+        }
+    }
+}
+
+impl CylinderMeshBuilder {
+    /// Creates a new [`CylinderMeshBuilder`] from a radius, a full height and a resolution.
+    #[inline]
+    pub fn new(radius: f32, height: f32, resolution: usize) -> Self {
+        Self {
+            cylinder: Cylinder::new(radius, height),
+            resolution,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub const fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    #[inline]
+    pub const fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    #[inline]
+    pub const fn caps(mut self, caps: bool) -> Self {
+        self.caps = caps;
+        self
+    }
+
+    #[inline]
+    pub const fn wireframe(mut self, wireframe: bool) -> Self {
+        self.wireframe = wireframe;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        assert!(
+            self.resolution > 2,
+            "cylinder must have a resolution of at least 3"
+        );
+        assert!(self.segments > 0, "cylinder must have at least 1 segment");
+
+        let Cylinder {
+            radius,
+            half_height,
+        } = self.cylinder;
+        let columns = self.resolution + 1;
+        let rows = self.segments + 1;
+        let step = TAU / self.resolution as f32;
+
+        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(rows * columns);
+        let mut normals: Vec<[f32; 3]> = Vec::with_capacity(rows * columns);
+        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(rows * columns);
+        let mut indices: Vec<u32> = Vec::new();
+
+        // The side, row by row from the top
+        for row in 0..rows {
+            let v = row as f32 / self.segments as f32;
+            let y = half_height - 2.0 * half_height * v;
+            for column in 0..columns {
+                let u = column as f32 / self.resolution as f32;
+                let (sin, cos) = (column as f32 * step).sin_cos();
+                positions.push([cos * radius, y, -sin * radius]);
+                normals.push([cos, 0.0, -sin]);
+                uvs.push([u, v]);
+            }
+        }
+        push_grid_indices(
+            &mut indices,
+            0,
+            rows,
+            columns,
+            self.wireframe,
+            (false, false),
+        );
+
+        if self.caps && !self.wireframe {
+            for (y, normal) in [(half_height, 1.0), (-half_height, -1.0)] {
+                let center = positions.len() as u32;
+                positions.push([0.0, y, 0.0]);
+                normals.push([0.0, normal, 0.0]);
+                uvs.push([0.5, 0.5]);
+
+                for column in 0..self.resolution {
+                    let (sin, cos) = (column as f32 * step).sin_cos();
+                    positions.push([cos * radius, y, -sin * radius]);
+                    normals.push([0.0, normal, 0.0]);
+                    uvs.push([0.5 + 0.5 * cos, 0.5 - 0.5 * sin * normal]);
+                }
+                push_cap_indices(&mut indices, center, self.resolution, normal > 0.0);
+            }
+        }
+
+        let primitive_topology = if self.wireframe {
+            wgpu::PrimitiveTopology::LineList
+        } else {
+            wgpu::PrimitiveTopology::TriangleList
+        };
+
+        Mesh::new(primitive_topology)
+            .with_inserted_indices(indices)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    }
+}
+
+impl Meshable for Cylinder {
+    type Output = CylinderMeshBuilder;
+    fn to_mesh_builder(&self) -> Self::Output {
+        CylinderMeshBuilder {
+            cylinder: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Cylinder> for Mesh {
+    fn from(cylinder: Cylinder) -> Self {
+        cylinder.to_mesh_builder().build()
+    }
+}
+
+impl From<CylinderMeshBuilder> for Mesh {
+    fn from(cylinder: CylinderMeshBuilder) -> Self {
+        cylinder.build()
+    }
+}
@@ -1,7 +1,74 @@
+pub mod capsule;
+pub mod cone;
 pub mod cuboid;
 pub mod cylinder;
+pub mod plane;
+pub mod polyline3d;
+pub mod segment;
 pub mod sphere;
+pub mod torus;
 
+pub use capsule::*;
+pub use cone::*;
 pub use cuboid::*;
 pub use cylinder::*;
+pub use plane::*;
+pub use polyline3d::*;
 pub use sphere::*;
+pub use torus::*;
+
+/// Push the indices of a grid of `rows` by `columns` vertices laid out row by row.
+///
+/// Solid grids are triangulated and wireframes get an edge along every row and column.
+/// A pole row, where all vertices coincide, gets no degenerate triangles and no ring edges.
+/// The last column of each row is expected to duplicate the first one, as a UV seam.
+pub(crate) fn push_grid_indices(
+    indices: &mut Vec<u32>,
+    offset: u32,
+    rows: usize,
+    columns: usize,
+    wireframe: bool,
+    poles: (bool, bool),
+) {
+    let index = |row: usize, column: usize| offset + (row * columns + column) as u32;
+    if wireframe {
+        for row in 0..rows {
+            let is_pole = (row == 0 && poles.0) || (row == rows - 1 && poles.1);
+            for column in 0..columns - 1 {
+                if !is_pole {
+                    indices.extend([index(row, column), index(row, column + 1)]);
+                }
+                if row + 1 < rows {
+                    indices.extend([index(row, column), index(row + 1, column)]);
+                }
+            }
+        }
+    } else {
+        for row in 0..rows - 1 {
+            for column in 0..columns - 1 {
+                let (a, b) = (index(row, column), index(row, column + 1));
+                let (c, d) = (index(row + 1, column), index(row + 1, column + 1));
+                if !(row == 0 && poles.0) {
+                    indices.extend([a, c, b]);
+                }
+                if !(row == rows - 2 && poles.1) {
+                    indices.extend([b, c, d]);
+                }
+            }
+        }
+    }
+}
+
+/// Push a triangle fan around the vertex at `center`, followed by a ring of `resolution` vertices
+/// going counterclockwise when seen from above
+pub(crate) fn push_cap_indices(indices: &mut Vec<u32>, center: u32, resolution: usize, up: bool) {
+    for i in 0..resolution as u32 {
+        let a = center + 1 + i;
+        let b = center + 1 + (i + 1) % resolution as u32;
+        if up {
+            indices.extend([center, a, b]);
+        } else {
+            indices.extend([center, b, a]);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::mesh::Mesh;
+use crate::primitive::Meshable;
+
+use super::push_grid_indices;
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+
+/// A builder used for creating a [`Mesh`] with a [`Plane3d`] shape.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlaneMeshBuilder {
+    /// The [`Plane3d`] shape.
+    pub plane: Plane3d,
+    /// The number of subdivisions along each side of the plane,
+    /// `0` gives a single quad.
+    pub subdivisions: usize,
+    pub wireframe: bool,
+}
+
+impl PlaneMeshBuilder {
+    /// Creates a new [`PlaneMeshBuilder`] from a normal and a full width and depth.
+    #[inline]
+    pub fn new(normal: Vec3, size: Vec2) -> Self {
+        Self {
+            plane: Plane3d::new(normal, size),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub const fn subdivisions(mut self, subdivisions: usize) -> Self {
+        self.subdivisions = subdivisions;
+        self
+    }
+
+    #[inline]
+    pub const fn wireframe(mut self, wireframe: bool) -> Self {
+        self.wireframe = wireframe;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        let count = self.subdivisions + 2;
+        let rotation = self.plane.rotation();
+        let size = self.plane.half_size * 2.0;
+        let normal: [f32; 3] = self.plane.normal.into();
+
+        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(count * count);
+        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(count * count);
+        let mut indices: Vec<u32> = Vec::new();
+
+        // Rows run from the far edge (-Z) to the near edge (+Z) of the unrotated plane
+        for row in 0..count {
+            let v = row as f32 / (count - 1) as f32;
+            for column in 0..count {
+                let u = column as f32 / (count - 1) as f32;
+                let local = Vec3::new((u - 0.5) * size.x, 0.0, (v - 0.5) * size.y);
+                positions.push((rotation * local).into());
+                uvs.push([u, v]);
+            }
+        }
+        push_grid_indices(
+            &mut indices,
+            0,
+            count,
+            count,
+            self.wireframe,
+            (false, false),
+        );
+        if self.wireframe {
+            // The grid skips the seam column, which is a real edge here
+            for row in 0..count - 1 {
+                let a = (row * count + count - 1) as u32;
+                indices.extend([a, a + count as u32]);
+            }
+        }
+
+        let primitive_topology = if self.wireframe {
+            wgpu::PrimitiveTopology::LineList
+        } else {
+            wgpu::PrimitiveTopology::TriangleList
+        };
+
+        Mesh::new(primitive_topology)
+            .with_inserted_indices(indices)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![normal; count * count])
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    }
+}
+
+impl Meshable for Plane3d {
+    type Output = PlaneMeshBuilder;
+    fn to_mesh_builder(&self) -> Self::Output {
+        PlaneMeshBuilder {
+            plane: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Plane3d> for Mesh {
+    fn from(plane: Plane3d) -> Self {
+        plane.to_mesh_builder().build()
+    }
+}
+
+impl From<PlaneMeshBuilder> for Mesh {
+    fn from(plane: PlaneMeshBuilder) -> Self {
+        plane.build()
+    }
+}
@@ -0,0 +1,214 @@
+use crate::indices::Indices;
+use crate::mesh::Mesh;
+use crate::primitive::Meshable;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct Polyline3dMeshBuilder<const N: usize> {
+    pub polyline: Polyline3d<N>,
+    /// Whether the last vertex connects back to the first one.
+    pub closed: bool,
+    /// Whether to build a `LineList` with every segment spelled out instead of a `LineStrip`.
+    pub line_list: bool,
+    /// Optional per-vertex colors, one for every vertex of the polyline.
+    pub colors: Option<Vec<[f32; 4]>>,
+}
+
+impl<const N: usize> Polyline3dMeshBuilder<N> {
+    #[inline]
+    pub fn new(vertices: impl IntoIterator<Item = Vec3>) -> Self {
+        Self {
+            polyline: Polyline3d::from_iter(vertices),
+            closed: false,
+            line_list: false,
+            colors: None,
+        }
+    }
+
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    pub fn line_list(mut self, line_list: bool) -> Self {
+        self.line_list = line_list;
+        self
+    }
+
+    pub fn colors(mut self, colors: impl IntoIterator<Item = [f32; 4]>) -> Self {
+        self.colors = Some(colors.into_iter().collect());
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        build_polyline3d(
+            &self.polyline.vertices,
+            self.closed,
+            self.line_list,
+            self.colors.as_deref(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BoxedPolyline3dMeshBuilder {
+    pub polyline: BoxedPolyline3d,
+    /// Whether the last vertex connects back to the first one.
+    pub closed: bool,
+    /// Whether to build a `LineList` with every segment spelled out instead of a `LineStrip`.
+    pub line_list: bool,
+    /// Optional per-vertex colors, one for every vertex of the polyline.
+    pub colors: Option<Vec<[f32; 4]>>,
+}
+
+impl BoxedPolyline3dMeshBuilder {
+    #[inline]
+    pub fn new(vertices: impl IntoIterator<Item = Vec3>) -> Self {
+        Self {
+            polyline: BoxedPolyline3d::from_iter(vertices),
+            ..Default::default()
+        }
+    }
+
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    pub fn line_list(mut self, line_list: bool) -> Self {
+        self.line_list = line_list;
+        self
+    }
+
+    pub fn colors(mut self, colors: impl IntoIterator<Item = [f32; 4]>) -> Self {
+        self.colors = Some(colors.into_iter().collect());
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        build_polyline3d(
+            &self.polyline.vertices,
+            self.closed,
+            self.line_list,
+            self.colors.as_deref(),
+        )
+    }
+}
+
+/// Build a line mesh through `vertices`.
+///
+/// The U coordinate runs from `0.0` to `1.0` along the length of the line, which makes dashes
+/// and gradients easy to do in a shader. Closed lines end on a copy of their first vertex with
+/// a U of `1.0`, so the closing segment doesn't run backwards.
+pub(crate) fn build_polyline3d(
+    vertices: &[Vec3],
+    closed: bool,
+    line_list: bool,
+    colors: Option<&[[f32; 4]]>,
+) -> Mesh {
+    let closed = closed && vertices.len() > 2;
+    let mut points = vertices.to_vec();
+    if closed {
+        points.push(vertices[0]);
+    }
+    let count = points.len();
+    let positions: Vec<[f32; 3]> = points.iter().map(|&v| v.into()).collect();
+    let normals = vec![[0.0, 0.0, 1.0]; count];
+
+    let mut distances = Vec::with_capacity(count);
+    let mut length = 0.0;
+    for (i, point) in points.iter().enumerate() {
+        if i > 0 {
+            length += point.distance(points[i - 1]);
+        }
+        distances.push(length);
+    }
+    let uvs: Vec<[f32; 2]> = distances
+        .iter()
+        .map(|&distance| {
+            let u = if length > 0.0 { distance / length } else { 0.0 };
+            [u, 0.0]
+        })
+        .collect();
+
+    let indices: Vec<u32> = if line_list {
+        (1..count as u32).flat_map(|i| [i - 1, i]).collect()
+    } else {
+        (0..count as u32).collect()
+    };
+
+    let primitive_topology = if line_list {
+        wgpu::PrimitiveTopology::LineList
+    } else {
+        wgpu::PrimitiveTopology::LineStrip
+    };
+
+    let mut mesh = Mesh::new(primitive_topology)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::fit(indices, count));
+    if let Some(colors) = colors {
+        assert_eq!(
+            colors.len(),
+            vertices.len(),
+            "polyline needs exactly one color per vertex"
+        );
+        let mut colors = colors.to_vec();
+        if closed {
+            colors.push(colors[0]);
+        }
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+    mesh
+}
+
+impl<const N: usize> Meshable for Polyline3d<N> {
+    type Output = Polyline3dMeshBuilder<N>;
+
+    fn to_mesh_builder(&self) -> Self::Output {
+        Polyline3dMeshBuilder {
+            polyline: *self,
+            closed: false,
+            line_list: false,
+            colors: None,
+        }
+    }
+}
+
+impl<const N: usize> From<Polyline3d<N>> for Mesh {
+    fn from(polyline: Polyline3d<N>) -> Self {
+        polyline.to_mesh_builder().build()
+    }
+}
+
+impl<const N: usize> From<Polyline3dMeshBuilder<N>> for Mesh {
+    fn from(polyline: Polyline3dMeshBuilder<N>) -> Self {
+        polyline.build()
+    }
+}
+
+impl Meshable for BoxedPolyline3d {
+    type Output = BoxedPolyline3dMeshBuilder;
+
+    fn to_mesh_builder(&self) -> Self::Output {
+        BoxedPolyline3dMeshBuilder {
+            polyline: self.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<BoxedPolyline3d> for Mesh {
+    fn from(polyline: BoxedPolyline3d) -> Self {
+        polyline.to_mesh_builder().build()
+    }
+}
+
+impl From<BoxedPolyline3dMeshBuilder> for Mesh {
+    fn from(polyline: BoxedPolyline3dMeshBuilder) -> Self {
+        polyline.build()
+    }
+}
@@ -0,0 +1,18 @@
+use crate::mesh::Mesh;
+use crate::primitive::Meshable;
+use amel_math::prelude::*;
+
+use super::polyline3d::Polyline3dMeshBuilder;
+
+impl Meshable for Segment3d {
+    type Output = Polyline3dMeshBuilder<2>;
+    fn to_mesh_builder(&self) -> Self::Output {
+        Polyline3dMeshBuilder::new([self.a(), self.b()]).line_list(true)
+    }
+}
+
+impl From<Segment3d> for Mesh {
+    fn from(segment: Segment3d) -> Self {
+        segment.to_mesh_builder().build()
+    }
+}
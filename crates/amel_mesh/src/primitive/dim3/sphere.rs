@@ -168,40 +168,24 @@ impl SphereMeshBuilder {
 
         let uvs = generated.raw_data().to_owned();
 
-        // let mut indices: Vec<u32> = Vec::with_capacity(generated.indices_per_main_triangle() * 20);
-
-        // for i in 0..20 {
-        //     generated.get_indices(i, &mut indices);
-        // }
-
-        let indices: Vec<u32> = if self.wireframe {
-            let mut wireframe_indices = Vec::new();
-            for i in 0..20 {
-                generated.get_line_indices(&mut wireframe_indices, i, 0, |buffer| {
-                    // Add a break in the line strip (you can modify this as necessary)
-                    buffer.push(u32::MAX);
-                });
-            }
-            wireframe_indices
-        } else {
-            let mut solid_indices = Vec::with_capacity(generated.indices_per_main_triangle() * 20);
-            for i in 0..20 {
-                generated.get_indices(i, &mut solid_indices);
-            }
-            solid_indices
-        };
-
-        let primitive_topology = if self.wireframe {
-            wgpu::PrimitiveTopology::LineStrip
-        } else {
-            wgpu::PrimitiveTopology::TriangleList
-        };
+        let mut indices: Vec<u32> = Vec::with_capacity(generated.indices_per_main_triangle() * 20);
+        for i in 0..20 {
+            generated.get_indices(i, &mut indices);
+        }
 
-        Ok(Mesh::new(primitive_topology)
+        let mesh = Mesh::new(wgpu::PrimitiveTopology::TriangleList)
             .with_inserted_indices(indices)
             .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, points)
             .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs))
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+
+        if self.wireframe {
+            Ok(mesh
+                .to_wireframe()
+                .expect("an icosphere is always a triangle mesh"))
+        } else {
+            Ok(mesh)
+        }
     }
 
     /// Creates a UV sphere [`Mesh`] with the given number of
@@ -264,9 +248,9 @@ impl SphereMeshBuilder {
 
         if self.wireframe {
             for i in 0..stacks {
-                let mut k1 = i * (sectors + 1);
-                let mut k2 = k1 + sectors + 1;
-                for _ in 0..sectors {
+                for j in 0..sectors {
+                    let k1 = i * (sectors + 1) + j;
+                    let k2 = k1 + sectors + 1;
                     if i != 0 {
                         indices.push(k1 as u32);
                         indices.push((k1 + 1) as u32);
@@ -277,16 +261,13 @@ impl SphereMeshBuilder {
                     }
                     indices.push(k1 as u32);
                     indices.push(k2 as u32);
-
-                    k1 += 1;
-                    k2 += 1;
                 }
             }
         } else {
             for i in 0..stacks {
-                let mut k1 = i * (sectors + 1);
-                let mut k2 = k1 + sectors + 1;
-                for _ in 0..sectors {
+                for j in 0..sectors {
+                    let k1 = i * (sectors + 1) + j;
+                    let k2 = k1 + sectors + 1;
                     if i != 0 {
                         indices.push(k1 as u32);
                         indices.push(k2 as u32);
@@ -297,8 +278,6 @@ impl SphereMeshBuilder {
                         indices.push(k2 as u32);
                         indices.push((k2 + 1) as u32);
                     }
-                    k1 += 1;
-                    k2 += 1;
                 }
             }
         }
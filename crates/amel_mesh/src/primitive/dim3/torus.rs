@@ -0,0 +1,138 @@
+use crate::mesh::Mesh;
+use crate::primitive::Meshable;
+
+use super::push_grid_indices;
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+
+/// A builder used for creating a [`Mesh`] with a [`Torus`] shape.
+#[derive(Clone, Copy, Debug)]
+pub struct TorusMeshBuilder {
+    /// The [`Torus`] shape.
+    pub torus: Torus,
+    /// The number of segments around the tube.
+    pub minor_resolution: usize,
+    /// The number of segments around the ring.
+    pub major_resolution: usize,
+    pub wireframe: bool,
+}
+
+impl Default for TorusMeshBuilder {
+    fn default() -> Self {
+        Self {
+            torus: Torus::default(),
+            minor_resolution: 24,
+            major_resolution: 32,
+            wireframe: false,
+        }
+    }
+}
+
+impl TorusMeshBuilder {
+    /// Creates a new [`TorusMeshBuilder`] from an inner and outer radius.
+    #[inline]
+    pub fn new(inner_radius: f32, outer_radius: f32) -> Self {
+        Self {
+            torus: Torus::new(inner_radius, outer_radius),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub const fn minor_resolution(mut self, resolution: usize) -> Self {
+        self.minor_resolution = resolution;
+        self
+    }
+
+    #[inline]
+    pub const fn major_resolution(mut self, resolution: usize) -> Self {
+        self.major_resolution = resolution;
+        self
+    }
+
+    #[inline]
+    pub const fn wireframe(mut self, wireframe: bool) -> Self {
+        self.wireframe = wireframe;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        assert!(
+            self.minor_resolution > 2 && self.major_resolution > 2,
+            "torus must have a resolution of at least 3"
+        );
+
+        let Torus {
+            minor_radius,
+            major_radius,
+        } = self.torus;
+        let rows = self.minor_resolution + 1;
+        let columns = self.major_resolution + 1;
+        let minor_step = TAU / self.minor_resolution as f32;
+        let major_step = TAU / self.major_resolution as f32;
+
+        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(rows * columns);
+        let mut normals: Vec<[f32; 3]> = Vec::with_capacity(rows * columns);
+        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(rows * columns);
+        let mut indices: Vec<u32> = Vec::new();
+
+        // Each row is a ring around the Y axis, starting from the top of the tube
+        // and continuing over its outside
+        for row in 0..rows {
+            let (tube_sin, tube_cos) = (FRAC_PI_2 - row as f32 * minor_step).sin_cos();
+            for column in 0..columns {
+                let (sin, cos) = (column as f32 * major_step).sin_cos();
+                let direction = Vec3::new(cos, 0.0, -sin);
+                let normal = direction * tube_cos + Vec3::Y * tube_sin;
+                positions.push((direction * major_radius + normal * minor_radius).into());
+                normals.push(normal.into());
+                uvs.push([
+                    column as f32 / self.major_resolution as f32,
+                    row as f32 / self.minor_resolution as f32,
+                ]);
+            }
+        }
+        push_grid_indices(
+            &mut indices,
+            0,
+            rows,
+            columns,
+            self.wireframe,
+            (false, false),
+        );
+
+        let primitive_topology = if self.wireframe {
+            wgpu::PrimitiveTopology::LineList
+        } else {
+            wgpu::PrimitiveTopology::TriangleList
+        };
+
+        Mesh::new(primitive_topology)
+            .with_inserted_indices(indices)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    }
+}
+
+impl Meshable for Torus {
+    type Output = TorusMeshBuilder;
+    fn to_mesh_builder(&self) -> Self::Output {
+        TorusMeshBuilder {
+            torus: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Torus> for Mesh {
+    fn from(torus: Torus) -> Self {
+        torus.to_mesh_builder().build()
+    }
+}
+
+impl From<TorusMeshBuilder> for Mesh {
+    fn from(torus: TorusMeshBuilder) -> Self {
+        torus.build()
+    }
+}
@@ -0,0 +1,489 @@
+use super::indices::Indices;
+use super::mesh::{position_groups, Mesh};
+use super::validation::MeshError;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// How much more a border edge resists being moved than a face of the same size.
+const BORDER_WEIGHT: f64 = 10.0;
+
+/// A simplified level of detail of a mesh.
+#[derive(Clone, Debug)]
+pub struct Lod {
+    pub mesh: Mesh,
+    /// An upper bound of how far any vertex of this level is from the planes of the original
+    /// triangles and borders it replaces, in the units of the positions
+    pub error: f32,
+}
+
+impl Mesh {
+    /// Simplify the mesh with quadric error metric edge collapses.
+    ///
+    /// Collapses continue until at most `target_ratio` of the triangles are left or every
+    /// remaining one would put a vertex further than `max_error` from the planes of the original
+    /// triangles and borders it replaces, in the units of the positions. The cheapest collapses
+    /// by quadric error go first. Returns the largest such distance, an upper bound for the
+    /// whole result.
+    ///
+    /// Vertices are collapsed onto their neighbours, so every remaining vertex keeps its
+    /// attributes. Vertices split by UV seams or hard edges never move, and border vertices
+    /// only move along the border, which keeps both intact.
+    ///
+    /// The result is an indexed `TriangleList` without unused vertices.
+    pub fn simplify(&mut self, target_ratio: f32, max_error: f32) -> Result<f32, MeshError> {
+        let mut triangles = self.triangles()?;
+        let positions = self.positions()?;
+        let (groups, group_count) = position_groups(positions);
+
+        let mut points = vec![DVec3::ZERO; group_count];
+        for (vertex, &group) in groups.iter().enumerate() {
+            points[group] = Vec3::from(positions[vertex]).as_dvec3();
+        }
+
+        let mut simplifier = Simplifier::new(&triangles, &groups, points);
+        let target = (triangles.len() as f32 * target_ratio.clamp(0.0, 1.0)).ceil() as usize;
+        let error = simplifier.run(&mut triangles, target, max_error as f64);
+
+        // Keep the used vertices in their original order
+        let mut remap = vec![u32::MAX; positions.len()];
+        for (triangle, _) in triangles
+            .iter()
+            .zip(&simplifier.alive)
+            .filter(|(_, &alive)| alive)
+        {
+            for &vertex in triangle {
+                remap[vertex as usize] = 0;
+            }
+        }
+        let mut unique = Vec::new();
+        for (vertex, slot) in remap.iter_mut().enumerate() {
+            if *slot == 0 {
+                *slot = unique.len() as u32;
+                unique.push(vertex as u32);
+            }
+        }
+        let indices: Vec<u32> = triangles
+            .iter()
+            .zip(&simplifier.alive)
+            .filter(|(_, &alive)| alive)
+            .flat_map(|(triangle, _)| triangle.map(|vertex| remap[vertex as usize]))
+            .collect();
+
+        for values in self.attributes.values_mut() {
+            *values = values.gather(&unique);
+        }
+        self.primitive_topology = wgpu::PrimitiveTopology::TriangleList;
+        self.indices = Some(Indices::fit(indices, unique.len()));
+        Ok(error as f32)
+    }
+
+    /// Build a level of detail for every one of `ratios`, each simplified from this mesh
+    /// with [`Mesh::simplify`] and limited to `max_error`.
+    pub fn lod_chain(&self, ratios: &[f32], max_error: f32) -> Result<Vec<Lod>, MeshError> {
+        ratios
+            .iter()
+            .map(|&ratio| {
+                let mut mesh = self.clone();
+                let error = mesh.simplify(ratio, max_error)?;
+                Ok(Lod { mesh, error })
+            })
+            .collect()
+    }
+}
+
+/// A symmetric 4x4 matrix measuring the squared distance to a set of weighted planes,
+/// followed by the total weight.
+///
+/// Its error is an average, good for ranking collapses but not a bound on the distance.
+#[derive(Clone, Copy, Debug, Default)]
+struct Quadric([f64; 11]);
+
+impl Quadric {
+    fn from_plane(normal: DVec3, point: DVec3, weight: f64) -> Self {
+        let [a, b, c] = normal.to_array();
+        let d = -normal.dot(point);
+        Self([
+            a * a,
+            a * b,
+            a * c,
+            a * d,
+            b * b,
+            b * c,
+            b * d,
+            c * c,
+            c * d,
+            d * d,
+            1.0,
+        ])
+        .scaled(weight)
+    }
+
+    fn scaled(self, factor: f64) -> Self {
+        Self(self.0.map(|value| value * factor))
+    }
+
+    fn add(&mut self, other: &Quadric) {
+        for (value, other) in self.0.iter_mut().zip(other.0) {
+            *value += other;
+        }
+    }
+
+    fn sum(mut self, other: &Quadric) -> Quadric {
+        self.add(other);
+        self
+    }
+
+    /// Get the weighted mean squared distance from `point` to the planes
+    fn error(&self, point: DVec3) -> f64 {
+        let [a2, ab, ac, ad, b2, bc, bd, c2, cd, d2, weight] = self.0;
+        if weight <= 0.0 {
+            return 0.0;
+        }
+        let DVec3 { x, y, z } = point;
+        let error = a2 * x * x
+            + 2.0 * ab * x * y
+            + 2.0 * ac * x * z
+            + 2.0 * ad * x
+            + b2 * y * y
+            + 2.0 * bc * y * z
+            + 2.0 * bd * y
+            + c2 * z * z
+            + 2.0 * cd * z
+            + d2;
+        (error / weight).max(0.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Collapse {
+    cost: f64,
+    from: usize,
+    to: usize,
+    /// The vertex of `to` that replaces the vertex of `from` in the remaining triangles
+    vertex: u32,
+    version: u32,
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collapse {
+    // Reversed so that the `BinaryHeap` pops the cheapest collapse first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.from.cmp(&self.from))
+    }
+}
+
+/// Edge collapse state, working on groups of vertices that share a position.
+struct Simplifier<'a> {
+    groups: &'a [usize],
+    points: Vec<DVec3>,
+    quadrics: Vec<Quadric>,
+    /// Every original triangle and border plane as a unit normal and an offset
+    planes: Vec<DVec4>,
+    /// The sorted indices into `planes` of the original triangles and borders each group replaces
+    group_planes: Vec<Vec<u32>>,
+    /// The triangles around each group, including removed ones until they are pruned
+    adjacency: Vec<Vec<usize>>,
+    alive: Vec<bool>,
+    /// Groups with several vertices or non-manifold edges, which never move
+    locked: Vec<bool>,
+    removed: Vec<bool>,
+    versions: Vec<u32>,
+}
+
+impl<'a> Simplifier<'a> {
+    fn new(triangles: &[[u32; 3]], groups: &'a [usize], points: Vec<DVec3>) -> Self {
+        let group_count = points.len();
+        let mut quadrics = vec![Quadric::default(); group_count];
+        let mut adjacency = vec![Vec::new(); group_count];
+        let mut locked = vec![false; group_count];
+        let mut wedges: Vec<Option<u32>> = vec![None; group_count];
+        let mut edges: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+        let mut planes = Vec::new();
+        let mut group_planes = vec![Vec::new(); group_count];
+        let mut add_plane = |normal: DVec3, point: DVec3, members: &[usize]| {
+            for &group in members {
+                group_planes[group].push(planes.len() as u32);
+            }
+            planes.push(normal.extend(-normal.dot(point)));
+        };
+
+        for (face, triangle) in triangles.iter().enumerate() {
+            let [a, b, c] = triangle.map(|vertex| groups[vertex as usize]);
+            let cross = (points[b] - points[a]).cross(points[c] - points[a]);
+            let area = cross.length() / 2.0;
+            let quadric = Quadric::from_plane(cross.normalize_or_zero(), points[a], area);
+            add_plane(cross.normalize_or_zero(), points[a], &[a, b, c]);
+
+            for (corner, group) in [a, b, c].into_iter().enumerate() {
+                quadrics[group].add(&quadric);
+                adjacency[group].push(face);
+
+                // A group with several vertices has an attribute seam running through it
+                let vertex = triangle[corner];
+                match wedges[group] {
+                    Some(wedge) if wedge != vertex => locked[group] = true,
+                    _ => wedges[group] = Some(vertex),
+                }
+
+                let next = [a, b, c][(corner + 1) % 3];
+                let entry = edges.entry((group.min(next), group.max(next))).or_default();
+                entry.0 += 1;
+                entry.1 = face;
+            }
+        }
+
+        for (&(a, b), &(count, face)) in &edges {
+            match count {
+                1 => {
+                    // Keep borders in place with a plane through the edge, perpendicular
+                    // to its face
+                    let [p, q, r] = triangles[face].map(|vertex| points[groups[vertex as usize]]);
+                    let normal = (q - p).cross(r - p).normalize_or_zero();
+                    let edge = points[b] - points[a];
+                    let plane = edge.cross(normal).normalize_or_zero();
+                    let quadric = Quadric::from_plane(
+                        plane,
+                        points[a],
+                        edge.length_squared() * BORDER_WEIGHT,
+                    );
+                    quadrics[a].add(&quadric);
+                    quadrics[b].add(&quadric);
+                    add_plane(plane, points[a], &[a, b]);
+                }
+                2 => {}
+                _ => {
+                    locked[a] = true;
+                    locked[b] = true;
+                }
+            }
+        }
+
+        Self {
+            groups,
+            points,
+            quadrics,
+            planes,
+            group_planes,
+            adjacency,
+            alive: vec![true; triangles.len()],
+            locked,
+            removed: vec![false; group_count],
+            versions: vec![0; group_count],
+        }
+    }
+
+    fn run(&mut self, triangles: &mut [[u32; 3]], target: usize, max_error: f64) -> f64 {
+        let mut heap = BinaryHeap::new();
+        for group in 0..self.points.len() {
+            heap.extend(self.best_collapse(triangles, group, max_error));
+        }
+
+        let mut triangle_count = triangles.len();
+        let mut error: f64 = 0.0;
+        while triangle_count > target {
+            let Some(collapse) = heap.pop() else {
+                break;
+            };
+            if self.removed[collapse.from] || collapse.version != self.versions[collapse.from] {
+                continue;
+            }
+            // `to` may have taken over more planes since the collapse was queued
+            let distance = self.plane_distance(collapse.from, collapse.to);
+            if distance > max_error {
+                continue;
+            }
+
+            let neighbours = self.neighbours(triangles, collapse.from);
+            for face in std::mem::take(&mut self.adjacency[collapse.from]) {
+                if !self.alive[face] {
+                    continue;
+                }
+                let groups = triangles[face].map(|vertex| self.groups[vertex as usize]);
+                if groups.contains(&collapse.to) {
+                    self.alive[face] = false;
+                    triangle_count -= 1;
+                } else {
+                    // Unlocked groups have a single vertex, so this replaces exactly one corner
+                    for vertex in triangles[face].iter_mut() {
+                        if self.groups[*vertex as usize] == collapse.from {
+                            *vertex = collapse.vertex;
+                        }
+                    }
+                    self.adjacency[collapse.to].push(face);
+                }
+            }
+
+            let quadric = self.quadrics[collapse.from];
+            self.quadrics[collapse.to].add(&quadric);
+            let from_planes = std::mem::take(&mut self.group_planes[collapse.from]);
+            let to_planes = &mut self.group_planes[collapse.to];
+            to_planes.extend(from_planes);
+            to_planes.sort_unstable();
+            to_planes.dedup();
+            self.removed[collapse.from] = true;
+            error = error.max(distance);
+
+            for group in neighbours {
+                if self.removed[group] {
+                    continue;
+                }
+                let alive = &self.alive;
+                self.adjacency[group].retain(|&face| alive[face]);
+                self.versions[group] += 1;
+                heap.extend(self.best_collapse(triangles, group, max_error));
+            }
+        }
+        error
+    }
+
+    /// Get the largest distance from the position of `to` to the planes both groups replace
+    fn plane_distance(&self, from: usize, to: usize) -> f64 {
+        let point = self.points[to].extend(1.0);
+        self.group_planes[from]
+            .iter()
+            .chain(&self.group_planes[to])
+            .map(|&plane| self.planes[plane as usize].dot(point).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Get the groups around `group` with the number of alive triangles each shares with it
+    fn edge_faces(&self, triangles: &[[u32; 3]], group: usize) -> HashMap<usize, usize> {
+        let mut counts = HashMap::new();
+        for &face in &self.adjacency[group] {
+            if !self.alive[face] {
+                continue;
+            }
+            for vertex in triangles[face] {
+                let other = self.groups[vertex as usize];
+                if other != group {
+                    *counts.entry(other).or_insert(0) += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    fn neighbours(&self, triangles: &[[u32; 3]], group: usize) -> Vec<usize> {
+        let mut neighbours: Vec<usize> = self.edge_faces(triangles, group).into_keys().collect();
+        neighbours.sort_unstable();
+        neighbours
+    }
+
+    fn best_collapse(
+        &self,
+        triangles: &[[u32; 3]],
+        from: usize,
+        max_error: f64,
+    ) -> Option<Collapse> {
+        if self.locked[from] || self.removed[from] {
+            return None;
+        }
+
+        let edges = self.edge_faces(triangles, from);
+        let borders = edges.values().filter(|&&count| count == 1).count();
+        if edges.values().any(|&count| count > 2) || !(borders == 0 || borders == 2) {
+            return None;
+        }
+
+        let mut best: Option<Collapse> = None;
+        for (&to, &count) in &edges {
+            // Border vertices may only slide along the border
+            if borders == 2 && count != 1 {
+                continue;
+            }
+            let cost = self.quadrics[from]
+                .sum(&self.quadrics[to])
+                .error(self.points[to]);
+            if best.is_some_and(|best| best.cost <= cost)
+                || self.plane_distance(from, to) > max_error
+            {
+                continue;
+            }
+            let Some(vertex) = self.target_vertex(triangles, from, to) else {
+                continue;
+            };
+            if !self.link_condition(triangles, from, to, count) || self.flips(triangles, from, to) {
+                continue;
+            }
+            best = Some(Collapse {
+                cost,
+                from,
+                to,
+                vertex,
+                version: self.versions[from],
+            });
+        }
+        best
+    }
+
+    /// Get the vertex of `to` that the triangles around `from` will use, which has to be the
+    /// same in every triangle of the collapsing edge
+    fn target_vertex(&self, triangles: &[[u32; 3]], from: usize, to: usize) -> Option<u32> {
+        let mut target = None;
+        for &face in &self.adjacency[from] {
+            if !self.alive[face] {
+                continue;
+            }
+            for vertex in triangles[face] {
+                if self.groups[vertex as usize] == to {
+                    match target {
+                        Some(target) if target != vertex => return None,
+                        _ => target = Some(vertex),
+                    }
+                }
+            }
+        }
+        target
+    }
+
+    /// Check that the ends of the edge only share the neighbours of the triangles on the
+    /// edge, otherwise the collapse would pinch the surface
+    fn link_condition(&self, triangles: &[[u32; 3]], from: usize, to: usize, faces: usize) -> bool {
+        let from_neighbours = self.edge_faces(triangles, from);
+        let shared = self
+            .edge_faces(triangles, to)
+            .keys()
+            .filter(|group| **group != from && from_neighbours.contains_key(group))
+            .count();
+        shared == faces
+    }
+
+    /// Check whether moving `from` onto `to` flips or collapses any remaining triangle
+    fn flips(&self, triangles: &[[u32; 3]], from: usize, to: usize) -> bool {
+        self.adjacency[from]
+            .iter()
+            .filter(|&&face| self.alive[face])
+            .any(|&face| {
+                let groups = triangles[face].map(|vertex| self.groups[vertex as usize]);
+                if groups.contains(&to) {
+                    return false;
+                }
+                let [a, b, c] = groups.map(|group| self.points[group]);
+                let before = (b - a).cross(c - a);
+                let [a, b, c] =
+                    groups.map(|group| self.points[if group == from { to } else { group }]);
+                let after = (b - a).cross(c - a);
+                after.dot(before) <= 0.0
+                    || after.length_squared() <= 1e-12 * before.length_squared()
+            })
+    }
+}
@@ -0,0 +1,459 @@
+use super::attribute::VertexAttributeValues;
+use super::indices::Indices;
+use super::mesh::{position_groups, Mesh};
+use super::validation::MeshError;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Weighted old vertices that make up a new vertex.
+type Stencil = Vec<(u32, f32)>;
+
+/// Sharp feature rules for [`Mesh::subdivide_loop_with_options`] and
+/// [`Mesh::subdivide_catmull_clark_with_options`].
+///
+/// Boundaries are always kept as curves, the options control what else stays sharp.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SubdivisionOptions {
+    /// Edges whose faces meet at more than this angle in radians become creases.
+    pub crease_angle: Option<f32>,
+    /// Keep boundary vertices in place instead of smoothing the boundary curve.
+    pub pin_boundaries: bool,
+}
+
+impl SubdivisionOptions {
+    pub const fn new() -> Self {
+        Self {
+            crease_angle: None,
+            pin_boundaries: false,
+        }
+    }
+
+    pub const fn crease_angle(mut self, crease_angle: f32) -> Self {
+        self.crease_angle = Some(crease_angle);
+        self
+    }
+
+    pub const fn pin_boundaries(mut self, pin_boundaries: bool) -> Self {
+        self.pin_boundaries = pin_boundaries;
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Scheme {
+    Loop,
+    CatmullClark,
+}
+
+impl Mesh {
+    /// Apply `levels` rounds of Loop subdivision, which splits every triangle into four and
+    /// smooths the surface.
+    ///
+    /// Positions are smoothed across UV seams, every other attribute is interpolated on its
+    /// own side of the seam. Normals are interpolated too, so meshes with hard edges need
+    /// [`Mesh::compute_smooth_normals`] afterwards to look smooth. Strips become an indexed
+    /// `TriangleList`.
+    pub fn subdivide_loop(&mut self, levels: usize) -> Result<(), MeshError> {
+        self.subdivide_loop_with_options(levels, SubdivisionOptions::default())
+    }
+
+    /// [`Mesh::subdivide_loop`] with creases and boundary rules.
+    pub fn subdivide_loop_with_options(
+        &mut self,
+        levels: usize,
+        options: SubdivisionOptions,
+    ) -> Result<(), MeshError> {
+        for _ in 0..levels {
+            self.subdivide(Scheme::Loop, options)?;
+        }
+        Ok(())
+    }
+
+    /// Apply `levels` rounds of Catmull-Clark subdivision, which splits every face into quads
+    /// and smooths the surface.
+    ///
+    /// Quads are recovered from consecutive triangles that share an edge, which is how the
+    /// builders and importers triangulate them, and the other triangles are kept as
+    /// triangles. The result is an indexed `TriangleList` with two triangles per quad, so it
+    /// can be subdivided again.
+    pub fn subdivide_catmull_clark(&mut self, levels: usize) -> Result<(), MeshError> {
+        self.subdivide_catmull_clark_with_options(levels, SubdivisionOptions::default())
+    }
+
+    /// [`Mesh::subdivide_catmull_clark`] with creases and boundary rules.
+    pub fn subdivide_catmull_clark_with_options(
+        &mut self,
+        levels: usize,
+        options: SubdivisionOptions,
+    ) -> Result<(), MeshError> {
+        for _ in 0..levels {
+            self.subdivide(Scheme::CatmullClark, options)?;
+        }
+        Ok(())
+    }
+
+    fn subdivide(&mut self, scheme: Scheme, options: SubdivisionOptions) -> Result<(), MeshError> {
+        let triangles = self.triangles()?;
+        let positions = self.positions()?;
+        let vertex_count = positions.len();
+        let faces = match scheme {
+            Scheme::Loop => triangles.iter().map(|triangle| triangle.to_vec()).collect(),
+            Scheme::CatmullClark => quads_from_triangles(&triangles),
+        };
+
+        // positions are refined on the welded surface, so that seams don't tear it apart
+        let (groups, group_count) = position_groups(positions);
+        let mut representatives = vec![0; group_count];
+        for (vertex, &group) in groups.iter().enumerate() {
+            representatives[group] = vertex as u32;
+        }
+        let group_faces: Vec<Vec<u32>> = faces
+            .iter()
+            .map(|face| {
+                face.iter()
+                    .map(|&vertex| groups[vertex as usize] as u32)
+                    .collect()
+            })
+            .collect();
+        let surface = Topology::new(&group_faces, group_count);
+
+        let group_position =
+            |group: u32| Vec3::from(positions[representatives[group as usize] as usize]);
+        let sharp: Vec<bool> = surface
+            .edges
+            .iter()
+            .map(|edge| match (edge.faces.as_slice(), options.crease_angle) {
+                ([a, b], Some(crease_angle)) => {
+                    let normal = |face: usize| {
+                        face_normal(group_faces[face].iter().map(|&group| group_position(group)))
+                    };
+                    normal(*a).angle_between(normal(*b)) > crease_angle
+                }
+                ([_, _], None) => false,
+                _ => true,
+            })
+            .collect();
+        let mut pinned = vec![false; group_count];
+        if options.pin_boundaries {
+            for edge in surface.edges.iter().filter(|edge| edge.faces.len() == 1) {
+                pinned[edge.ends[0] as usize] = true;
+                pinned[edge.ends[1] as usize] = true;
+            }
+        }
+        let surface_refinement = surface.refine(scheme, &sharp, &pinned);
+
+        // everything else is refined per vertex, with seams acting as boundaries
+        let topology = Topology::new(&faces, vertex_count);
+        let vertex_sharp: Vec<bool> = topology
+            .edges
+            .iter()
+            .map(|edge| {
+                let [a, b] = edge.ends.map(|vertex| groups[vertex as usize] as u32);
+                edge.faces.len() != 2 || surface.edge(a, b).is_none_or(|edge| sharp[edge])
+            })
+            .collect();
+        let vertex_pinned: Vec<bool> = groups.iter().map(|&group| pinned[group]).collect();
+        let refinement = topology.refine(scheme, &vertex_sharp, &vertex_pinned);
+
+        let to_vertices = |stencil: &Stencil| -> Stencil {
+            stencil
+                .iter()
+                .map(|&(group, weight)| (representatives[group as usize], weight))
+                .collect()
+        };
+        let mut stencils = refinement.vertices;
+        let mut position_stencils: Vec<Stencil> = groups
+            .iter()
+            .map(|&group| to_vertices(&surface_refinement.vertices[group]))
+            .collect();
+
+        let edge_offset = vertex_count;
+        stencils.extend(refinement.edges);
+        for edge in &topology.edges {
+            let [a, b] = edge.ends.map(|vertex| groups[vertex as usize] as u32);
+            let stencil = match surface.edge(a, b) {
+                Some(edge) => to_vertices(&surface_refinement.edges[edge]),
+                None => vec![(edge.ends[0], 0.5), (edge.ends[1], 0.5)],
+            };
+            position_stencils.push(stencil);
+        }
+
+        let face_offset = edge_offset + topology.edges.len();
+        let mut indices = Vec::new();
+        match scheme {
+            Scheme::Loop => {
+                for face in &faces {
+                    let [a, b, c] = [face[0], face[1], face[2]];
+                    let edge = |a, b| topology.edge(a, b).map(|edge| (edge_offset + edge) as u32);
+                    let (Some(ab), Some(bc), Some(ca)) = (edge(a, b), edge(b, c), edge(c, a))
+                    else {
+                        // degenerate triangles have nothing to split
+                        continue;
+                    };
+                    indices.extend_from_slice(&[a, ab, ca, ab, b, bc, ca, bc, c, ab, bc, ca]);
+                }
+            }
+            Scheme::CatmullClark => {
+                stencils.extend(refinement.faces);
+                position_stencils.extend(surface_refinement.faces.iter().map(to_vertices));
+                for (index, face) in faces.iter().enumerate() {
+                    let center = (face_offset + index) as u32;
+                    let edge = |a, b| topology.edge(a, b).map(|edge| (edge_offset + edge) as u32);
+                    for (corner, &vertex) in face.iter().enumerate() {
+                        let next = face[(corner + 1) % face.len()];
+                        let previous = face[(corner + face.len() - 1) % face.len()];
+                        let (Some(outgoing), Some(incoming)) =
+                            (edge(vertex, next), edge(previous, vertex))
+                        else {
+                            continue;
+                        };
+                        indices.extend_from_slice(&[
+                            vertex, outgoing, center, vertex, center, incoming,
+                        ]);
+                    }
+                }
+            }
+        }
+
+        for (&location, values) in self.attributes.iter_mut() {
+            if location == Mesh::ATTRIBUTE_POSITION {
+                *values = values.blend(&position_stencils);
+            } else {
+                *values = values.blend(&stencils);
+            }
+        }
+        // blended directions are shorter than unit length
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            for normal in normals.iter_mut() {
+                *normal = Vec3::from(*normal).normalize_or_zero().into();
+            }
+        }
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            for tangent in tangents.iter_mut() {
+                let direction = Vec3::from_slice(tangent).normalize_or_zero();
+                *tangent = direction.extend(tangent[3].signum()).to_array();
+            }
+        }
+
+        self.primitive_topology = wgpu::PrimitiveTopology::TriangleList;
+        self.indices = Some(Indices::fit(indices, stencils.len()));
+        Ok(())
+    }
+}
+
+/// Pair consecutive triangles that share an edge back into quads.
+fn quads_from_triangles(triangles: &[[u32; 3]]) -> Vec<Vec<u32>> {
+    let mut faces = Vec::with_capacity(triangles.len());
+    let mut index = 0;
+    while index < triangles.len() {
+        if let Some(quad) = triangles
+            .get(index + 1)
+            .and_then(|next| quad_from_pair(triangles[index], *next))
+        {
+            faces.push(quad);
+            index += 2;
+        } else {
+            faces.push(triangles[index].to_vec());
+            index += 1;
+        }
+    }
+    faces
+}
+
+fn quad_from_pair(a: [u32; 3], b: [u32; 3]) -> Option<Vec<u32>> {
+    for i in 0..3 {
+        let (r, p, q) = (a[i], a[(i + 1) % 3], a[(i + 2) % 3]);
+        for j in 0..3 {
+            let s = b[j];
+            if b[(j + 1) % 3] == q && b[(j + 2) % 3] == p && s != r && s != p && s != q {
+                return Some(vec![r, p, s, q]);
+            }
+        }
+    }
+    None
+}
+
+/// Normal of a polygon that doesn't have to be planar.
+fn face_normal(corners: impl Iterator<Item = Vec3>) -> Vec3 {
+    let corners: Vec<Vec3> = corners.collect();
+    (0..corners.len())
+        .map(|index| corners[index].cross(corners[(index + 1) % corners.len()]))
+        .sum::<Vec3>()
+        .normalize_or_zero()
+}
+
+struct Edge {
+    ends: [u32; 2],
+    faces: Vec<usize>,
+}
+
+/// Edge and face adjacency of a polygon mesh.
+struct Topology<'a> {
+    faces: &'a [Vec<u32>],
+    edges: Vec<Edge>,
+    edge_ids: HashMap<(u32, u32), usize>,
+    vertex_edges: Vec<Vec<usize>>,
+    vertex_faces: Vec<Vec<usize>>,
+}
+
+/// New vertices of one subdivision step, for every old vertex, edge and face.
+struct Refinement {
+    vertices: Vec<Stencil>,
+    edges: Vec<Stencil>,
+    faces: Vec<Stencil>,
+}
+
+impl<'a> Topology<'a> {
+    fn new(faces: &'a [Vec<u32>], vertex_count: usize) -> Self {
+        let mut topology = Self {
+            faces,
+            edges: Vec::new(),
+            edge_ids: HashMap::new(),
+            vertex_edges: vec![Vec::new(); vertex_count],
+            vertex_faces: vec![Vec::new(); vertex_count],
+        };
+        for (index, face) in faces.iter().enumerate() {
+            let mut seen = HashSet::new();
+            for (corner, &a) in face.iter().enumerate() {
+                if seen.insert(a) {
+                    topology.vertex_faces[a as usize].push(index);
+                }
+                let b = face[(corner + 1) % face.len()];
+                if a == b {
+                    continue;
+                }
+                let key = (a.min(b), a.max(b));
+                let edge = *topology.edge_ids.entry(key).or_insert_with(|| {
+                    topology.edges.push(Edge {
+                        ends: [key.0, key.1],
+                        faces: Vec::new(),
+                    });
+                    topology.vertex_edges[a as usize].push(topology.edges.len() - 1);
+                    topology.vertex_edges[b as usize].push(topology.edges.len() - 1);
+                    topology.edges.len() - 1
+                });
+                topology.edges[edge].faces.push(index);
+            }
+        }
+        topology
+    }
+
+    fn edge(&self, a: u32, b: u32) -> Option<usize> {
+        self.edge_ids.get(&(a.min(b), a.max(b))).copied()
+    }
+
+    fn refine(&self, scheme: Scheme, sharp: &[bool], pinned: &[bool]) -> Refinement {
+        let faces: Vec<Stencil> = self
+            .faces
+            .iter()
+            .map(|face| {
+                let weight = 1.0 / face.len() as f32;
+                face.iter().map(|&vertex| (vertex, weight)).collect()
+            })
+            .collect();
+
+        let edges = self
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(index, edge)| {
+                let [a, b] = edge.ends;
+                if sharp[index] || edge.faces.len() != 2 {
+                    return vec![(a, 0.5), (b, 0.5)];
+                }
+                match scheme {
+                    Scheme::Loop => {
+                        let mut stencil = vec![(a, 0.375), (b, 0.375)];
+                        for &face in &edge.faces {
+                            let opposite = self.faces[face]
+                                .iter()
+                                .find(|&&vertex| vertex != a && vertex != b);
+                            stencil.extend(opposite.map(|&vertex| (vertex, 0.125)));
+                        }
+                        stencil
+                    }
+                    Scheme::CatmullClark => {
+                        let mut stencil = vec![(a, 0.25), (b, 0.25)];
+                        for &face in &edge.faces {
+                            stencil.extend(
+                                faces[face]
+                                    .iter()
+                                    .map(|&(vertex, weight)| (vertex, weight * 0.25)),
+                            );
+                        }
+                        stencil
+                    }
+                }
+            })
+            .collect();
+
+        let vertices = (0..self.vertex_edges.len())
+            .map(|vertex| {
+                let edges = &self.vertex_edges[vertex];
+                let vertex = vertex as u32;
+                let other = |edge: usize| {
+                    let [a, b] = self.edges[edge].ends;
+                    if a == vertex {
+                        b
+                    } else {
+                        a
+                    }
+                };
+                let creases: Vec<usize> =
+                    edges.iter().copied().filter(|&edge| sharp[edge]).collect();
+                if pinned[vertex as usize] || creases.len() > 2 || edges.is_empty() {
+                    return vec![(vertex, 1.0)];
+                }
+                if creases.len() == 2 {
+                    return vec![
+                        (vertex, 0.75),
+                        (other(creases[0]), 0.125),
+                        (other(creases[1]), 0.125),
+                    ];
+                }
+
+                let n = edges.len() as f32;
+                match scheme {
+                    Scheme::Loop => {
+                        let beta = if edges.len() == 3 {
+                            3.0 / 16.0
+                        } else {
+                            3.0 / (8.0 * n)
+                        };
+                        let mut stencil = vec![(vertex, 1.0 - n * beta)];
+                        stencil.extend(edges.iter().map(|&edge| (other(edge), beta)));
+                        stencil
+                    }
+                    Scheme::CatmullClark => {
+                        // (F + 2R + (n - 3)P) / n, with F the average face point and R the
+                        // average edge midpoint
+                        let vertex_faces = &self.vertex_faces[vertex as usize];
+                        let mut stencil = vec![(vertex, (n - 3.0) / n + 1.0 / n)];
+                        stencil.extend(edges.iter().map(|&edge| (other(edge), 1.0 / (n * n))));
+                        let face_weight = 1.0 / (n * vertex_faces.len() as f32);
+                        for &face in vertex_faces {
+                            stencil.extend(
+                                faces[face]
+                                    .iter()
+                                    .map(|&(corner, weight)| (corner, weight * face_weight)),
+                            );
+                        }
+                        stencil
+                    }
+                }
+            })
+            .collect();
+
+        Refinement {
+            vertices,
+            edges,
+            faces,
+        }
+    }
+}
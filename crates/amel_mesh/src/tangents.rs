@@ -0,0 +1,117 @@
+use super::indices::Indices;
+use super::mesh::Mesh;
+use super::validation::MeshError;
+
+use amel_gpu::prelude::*;
+use std::collections::HashMap;
+
+/// The triangles of a mesh as seen by MikkTSpace, which writes a tangent for every corner
+struct MikkTSpaceGeometry<'a> {
+    triangles: &'a [[u32; 3]],
+    positions: &'a [[f32; 3]],
+    normals: &'a [[f32; 3]],
+    uvs: &'a [[f32; 2]],
+    tangents: Vec<[f32; 4]>,
+}
+
+impl MikkTSpaceGeometry<'_> {
+    #[inline(always)]
+    fn vertex(&self, face: usize, vert: usize) -> usize {
+        self.triangles[face][vert] as usize
+    }
+}
+
+impl bevy_mikktspace::Geometry for MikkTSpaceGeometry<'_> {
+    fn num_faces(&self) -> usize {
+        self.triangles.len()
+    }
+
+    fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.positions[self.vertex(face, vert)]
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.normals[self.vertex(face, vert)]
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        self.uvs[self.vertex(face, vert)]
+    }
+
+    fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        self.tangents[face * 3 + vert] = tangent;
+    }
+}
+
+impl Mesh {
+    /// Generate MikkTSpace tangents and write them to [`Mesh::ATTRIBUTE_TANGENT`].
+    ///
+    /// Every tangent is a `Float32x4` whose `w` is the handedness, the bitangent being
+    /// `cross(normal, tangent.xyz) * tangent.w`. Requires positions, normals and
+    /// [`Mesh::ATTRIBUTE_UV_0`] on a triangle mesh.
+    ///
+    /// MikkTSpace computes a tangent for every triangle corner. When the corners sharing a
+    /// vertex disagree, like across a mirrored UV seam, the vertex is split so each copy keeps
+    /// its own tangent and the mesh becomes an indexed `TriangleList`. Existing vertices keep
+    /// their index, the copies are added after them.
+    pub fn generate_tangents(&mut self) -> Result<(), MeshError> {
+        let triangles = self.triangles()?;
+        let vertex_count = self.positions()?.len();
+
+        let mut geometry = MikkTSpaceGeometry {
+            triangles: &triangles,
+            positions: self.positions()?,
+            normals: self.float32x3_attribute(Mesh::ATTRIBUTE_NORMAL)?,
+            uvs: self.float32x2_attribute(Mesh::ATTRIBUTE_UV_0)?,
+            tangents: vec![[0.0; 4]; triangles.len() * 3],
+        };
+        if !bevy_mikktspace::generate_tangents(&mut geometry) {
+            return Err(MeshError::DegenerateTangentSpace);
+        }
+        let corner_tangents = geometry.tangents;
+
+        // The first tangent seen at a vertex stays on it, any other one gets a copy of the vertex
+        let mut tangents: Vec<Option<[f32; 4]>> = vec![None; vertex_count];
+        let mut sources: Vec<u32> = (0..vertex_count as u32).collect();
+        let mut copies = HashMap::new();
+        let corners: Vec<u32> = triangles
+            .iter()
+            .flatten()
+            .zip(&corner_tangents)
+            .map(|(&vertex, &tangent)| match tangents[vertex as usize] {
+                None => {
+                    tangents[vertex as usize] = Some(tangent);
+                    vertex
+                }
+                Some(existing) if existing == tangent => vertex,
+                Some(_) => *copies
+                    .entry((vertex, tangent.map(f32::to_bits)))
+                    .or_insert_with(|| {
+                        sources.push(vertex);
+                        tangents.push(Some(tangent));
+                        sources.len() as u32 - 1
+                    }),
+            })
+            .collect();
+
+        // Vertices no triangle uses still get a valid frame
+        let tangents: Vec<[f32; 4]> = tangents
+            .into_iter()
+            .map(|tangent| tangent.unwrap_or([1.0, 0.0, 0.0, 1.0]))
+            .collect();
+
+        if sources.len() > vertex_count {
+            for values in self.attributes.values_mut() {
+                *values = values.gather(&sources);
+            }
+            self.primitive_topology = wgpu::PrimitiveTopology::TriangleList;
+            self.indices = Some(Indices::fit(corners, sources.len()));
+        }
+        self.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+        Ok(())
+    }
+}
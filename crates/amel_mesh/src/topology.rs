@@ -0,0 +1,123 @@
+use super::indices::Indices;
+use super::mesh::Mesh;
+use super::validation::MeshError;
+
+use amel_gpu::prelude::*;
+
+impl Mesh {
+    /// Get the vertex indices of every triangle, counterclockwise for front faces.
+    ///
+    /// Strips are unrolled, skipping their restarts and degenerate joining triangles.
+    pub fn triangles(&self) -> Result<Vec<[u32; 3]>, MeshError> {
+        match self.primitive_topology {
+            wgpu::PrimitiveTopology::TriangleList => Ok(self
+                .element_indices()
+                .chunks_exact(3)
+                .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+                .collect()),
+            wgpu::PrimitiveTopology::TriangleStrip => {
+                let mut triangles = Vec::new();
+                for strip in self.strips() {
+                    for (i, window) in strip.windows(3).enumerate() {
+                        let [a, b, c] = [window[0], window[1], window[2]];
+                        if a == b || b == c || a == c {
+                            continue;
+                        }
+                        triangles.push(if i % 2 == 0 { [a, b, c] } else { [b, a, c] });
+                    }
+                }
+                Ok(triangles)
+            }
+            topology => Err(MeshError::UnsupportedTopology { topology }),
+        }
+    }
+
+    /// Get the vertex indices of every line segment.
+    ///
+    /// Strips are unrolled, skipping their restarts.
+    pub fn lines(&self) -> Result<Vec<[u32; 2]>, MeshError> {
+        match self.primitive_topology {
+            wgpu::PrimitiveTopology::LineList => Ok(self
+                .element_indices()
+                .chunks_exact(2)
+                .map(|line| [line[0], line[1]])
+                .collect()),
+            wgpu::PrimitiveTopology::LineStrip => Ok(self
+                .strips()
+                .iter()
+                .flat_map(|strip| strip.windows(2).map(|line| [line[0], line[1]]))
+                .collect()),
+            topology => Err(MeshError::UnsupportedTopology { topology }),
+        }
+    }
+
+    /// Convert a triangle strip into a `TriangleList` sharing the same vertices.
+    ///
+    /// Every part of a strip split by primitive restarts becomes its own set of triangles.
+    pub fn to_triangle_list(&self) -> Result<Mesh, MeshError> {
+        let indices = self.triangles()?.into_iter().flatten().collect();
+        Ok(self.with_topology(wgpu::PrimitiveTopology::TriangleList, indices))
+    }
+
+    /// Convert a line strip into a `LineList` sharing the same vertices.
+    ///
+    /// Every part of a strip split by primitive restarts becomes its own set of lines.
+    pub fn to_line_list(&self) -> Result<Mesh, MeshError> {
+        let indices = self.lines()?.into_iter().flatten().collect();
+        Ok(self.with_topology(wgpu::PrimitiveTopology::LineList, indices))
+    }
+
+    /// Get a non-indexed `PointList` with a point for every vertex of this mesh
+    pub fn to_point_list(&self) -> Mesh {
+        let mut points = Mesh::new(wgpu::PrimitiveTopology::PointList);
+        points.attributes = self.attributes.clone();
+        points
+    }
+
+    /// Convert strips into the matching list topology, expanding their primitive restarts.
+    ///
+    /// Lists are returned unchanged. Lists draw the same with any pipeline strip index format,
+    /// which makes this the easy way to mix meshes from different sources.
+    pub fn expand_strips(&self) -> Mesh {
+        match self.primitive_topology {
+            wgpu::PrimitiveTopology::TriangleStrip => self.to_triangle_list(),
+            wgpu::PrimitiveTopology::LineStrip => self.to_line_list(),
+            _ => Ok(self.clone()),
+        }
+        .expect("strips always convert to lists")
+    }
+
+    /// Get the indices, or the vertices in order for non-indexed meshes
+    fn element_indices(&self) -> Vec<u32> {
+        match &self.indices {
+            Some(indices) => indices.iter().collect(),
+            None => (0..self.count_vertices() as u32).collect(),
+        }
+    }
+
+    /// Get the parts of a strip that are separated by primitive restarts
+    fn strips(&self) -> Vec<Vec<u32>> {
+        let restart = self.indices.as_ref().map(Indices::restart_value);
+        self.element_indices()
+            .split(|&index| Some(index) == restart)
+            .map(<[u32]>::to_vec)
+            .collect()
+    }
+
+    /// Get a mesh with the same vertices drawn with `topology` and `indices`.
+    ///
+    /// 16 bit indices are kept, they can still address every vertex.
+    pub(crate) fn with_topology(
+        &self,
+        primitive_topology: wgpu::PrimitiveTopology,
+        indices: Vec<u32>,
+    ) -> Mesh {
+        let mut mesh = Mesh::new(primitive_topology);
+        mesh.attributes = self.attributes.clone();
+        mesh.indices = Some(match self.indices {
+            Some(Indices::U16(_)) => Indices::U16(indices.into_iter().map(|i| i as u16).collect()),
+            _ => Indices::U32(indices),
+        });
+        mesh
+    }
+}
@@ -0,0 +1,123 @@
+use super::attribute::VertexAttributeValues;
+use super::indices::Indices;
+use super::mesh::Mesh;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+
+impl Mesh {
+    /// Transform the mesh in place.
+    ///
+    /// Positions are transformed as points, normals by the inverse-transpose and tangents by
+    /// the upper 3x3 of `transform`, with both renormalized. A transform that mirrors the mesh
+    /// also flips the winding order and the tangent handedness, so front faces and bitangents
+    /// still point the right way.
+    pub fn transform(&mut self, transform: &Mat4) {
+        if *transform == Mat4::IDENTITY {
+            return;
+        }
+
+        if let Some(VertexAttributeValues::Float32x3(positions)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            for position in positions {
+                *position = transform.transform_point3(Vec3::from(*position)).into();
+            }
+        }
+
+        let matrix = Mat3::from_mat4(*transform);
+        let mirrored = matrix.determinant() < 0.0;
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            let normal_matrix = matrix.inverse().transpose();
+            for normal in normals {
+                *normal = (normal_matrix * Vec3::from(*normal))
+                    .normalize_or_zero()
+                    .into();
+            }
+        }
+
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            for tangent in tangents {
+                let direction =
+                    (matrix * Vec3::new(tangent[0], tangent[1], tangent[2])).normalize_or_zero();
+                let handedness = if mirrored { -tangent[3] } else { tangent[3] };
+                *tangent = direction.extend(handedness).into();
+            }
+        }
+
+        if mirrored {
+            self.flip_winding();
+        }
+    }
+
+    /// Translate the mesh in place
+    #[inline]
+    pub fn translate(&mut self, translation: Vec3) {
+        self.transform(&Mat4::from_translation(translation));
+    }
+
+    /// Rotate the mesh in place around the origin
+    #[inline]
+    pub fn rotate(&mut self, rotation: Quat) {
+        self.transform(&Mat4::from_quat(rotation));
+    }
+
+    /// Scale the mesh in place, negative factors mirror it
+    #[inline]
+    pub fn scale(&mut self, scale: Vec3) {
+        self.transform(&Mat4::from_scale(scale));
+    }
+
+    /// Reverse the winding order of every triangle, leaving line and point meshes untouched.
+    pub fn flip_winding(&mut self) {
+        match self.primitive_topology {
+            wgpu::PrimitiveTopology::TriangleList => match &mut self.indices {
+                Some(Indices::U16(indices)) => flip_triangle_list(indices),
+                Some(Indices::U32(indices)) => flip_triangle_list(indices),
+                None => {
+                    for triangle in 0..self.count_vertices() / 3 {
+                        for values in self.attributes.values_mut() {
+                            values.swap(triangle * 3 + 1, triangle * 3 + 2);
+                        }
+                    }
+                }
+            },
+            wgpu::PrimitiveTopology::TriangleStrip => {
+                let vertex_count = self.count_vertices() as u32;
+                let indices = self
+                    .indices
+                    .get_or_insert_with(|| Indices::U32((0..vertex_count).collect()));
+                match indices {
+                    Indices::U16(indices) => *indices = flip_triangle_strip(indices, u16::MAX),
+                    Indices::U32(indices) => *indices = flip_triangle_strip(indices, u32::MAX),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn flip_triangle_list<T>(indices: &mut [T]) {
+    for triangle in indices.chunks_exact_mut(3) {
+        triangle.swap(1, 2);
+    }
+}
+
+/// Repeat the first index of every strip, the extra degenerate triangle shifts the parity
+/// of all the following ones and with it their winding.
+fn flip_triangle_strip<T: Copy + PartialEq>(indices: &[T], restart: T) -> Vec<T> {
+    let mut flipped = Vec::with_capacity(indices.len() + 1);
+    let mut strip_start = true;
+    for &index in indices {
+        if strip_start && index != restart {
+            flipped.push(index);
+        }
+        strip_start = index == restart;
+        flipped.push(index);
+    }
+    flipped
+}
@@ -0,0 +1,174 @@
+use super::attribute::VertexAttributeValues;
+use super::indices::Indices;
+use super::mesh::Mesh;
+
+use amel_gpu::prelude::*;
+use thiserror::Error;
+
+/// An error describing why a [`Mesh`] can't be drawn as it is.
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum MeshError {
+    /// A required attribute is missing.
+    #[error("mesh is missing the attribute at location {location}")]
+    MissingAttribute { location: u32 },
+    /// An attribute has a format that doesn't fit its location.
+    #[error("attribute at location {location} has format {found:?} but {expected:?} is required")]
+    InvalidAttributeFormat {
+        location: u32,
+        expected: wgpu::VertexFormat,
+        found: wgpu::VertexFormat,
+    },
+    /// An attribute has a different number of vertices than the positions.
+    #[error("attribute at location {location} has {count} vertices but the mesh has {expected}")]
+    VertexCountMismatch {
+        location: u32,
+        count: usize,
+        expected: usize,
+    },
+    /// An index points past the last vertex.
+    #[error("index {index} at position {position} is out of range for {vertex_count} vertices")]
+    IndexOutOfRange {
+        position: usize,
+        index: u32,
+        vertex_count: usize,
+    },
+    /// The number of indices, or vertices for non-indexed meshes, doesn't suit the topology.
+    #[error("{count} elements can't be drawn as {topology:?}")]
+    InvalidElementCount {
+        topology: wgpu::PrimitiveTopology,
+        count: usize,
+    },
+    /// The mesh has more vertices than 16 bit indices can address.
+    #[error("{vertex_count} vertices can't be addressed with 16 bit indices")]
+    IndexOverflow { vertex_count: usize },
+    /// The operation only works on meshes of other topologies.
+    #[error("operation is not supported for {topology:?} meshes")]
+    UnsupportedTopology { topology: wgpu::PrimitiveTopology },
+    /// Two meshes with different topologies can't be combined.
+    #[error("cannot combine a {a:?} mesh with a {b:?} mesh")]
+    IncompatibleTopology {
+        a: wgpu::PrimitiveTopology,
+        b: wgpu::PrimitiveTopology,
+    },
+    /// MikkTSpace found no triangle to build a tangent space from.
+    #[error("tangents can't be generated for a mesh without non-degenerate triangles")]
+    DegenerateTangentSpace,
+}
+
+impl Mesh {
+    /// Get the positions, failing if they are missing or not [`wgpu::VertexFormat::Float32x3`]
+    pub(crate) fn positions(&self) -> Result<&[[f32; 3]], MeshError> {
+        self.float32x3_attribute(Mesh::ATTRIBUTE_POSITION)
+    }
+
+    pub(crate) fn float32x3_attribute(&self, location: u32) -> Result<&[[f32; 3]], MeshError> {
+        match self.attribute(location) {
+            Some(VertexAttributeValues::Float32x3(values)) => Ok(values),
+            values => Err(attribute_error(
+                location,
+                values,
+                wgpu::VertexFormat::Float32x3,
+            )),
+        }
+    }
+
+    pub(crate) fn float32x2_attribute(&self, location: u32) -> Result<&[[f32; 2]], MeshError> {
+        match self.attribute(location) {
+            Some(VertexAttributeValues::Float32x2(values)) => Ok(values),
+            values => Err(attribute_error(
+                location,
+                values,
+                wgpu::VertexFormat::Float32x2,
+            )),
+        }
+    }
+
+    /// Check that the mesh is well formed and can be drawn as it is.
+    ///
+    /// All attributes need the same number of vertices, positions need to be
+    /// [`wgpu::VertexFormat::Float32x3`], and every index needs to be in range. Strip topologies
+    /// may use the primitive restart value of their index format to break the strip.
+    pub fn validate(&self) -> Result<(), MeshError> {
+        let positions =
+            self.attribute(Mesh::ATTRIBUTE_POSITION)
+                .ok_or(MeshError::MissingAttribute {
+                    location: Mesh::ATTRIBUTE_POSITION,
+                })?;
+        if !matches!(positions, VertexAttributeValues::Float32x3(_)) {
+            return Err(MeshError::InvalidAttributeFormat {
+                location: Mesh::ATTRIBUTE_POSITION,
+                expected: wgpu::VertexFormat::Float32x3,
+                found: positions.into(),
+            });
+        }
+
+        let vertex_count = positions.len();
+        let mut locations: Vec<_> = self.attributes.keys().copied().collect();
+        locations.sort_unstable();
+        for location in locations {
+            let count = self.attributes[&location].len();
+            if count != vertex_count {
+                return Err(MeshError::VertexCountMismatch {
+                    location,
+                    count,
+                    expected: vertex_count,
+                });
+            }
+        }
+
+        let strip = self.primitive_topology.is_strip();
+        let element_count = match &self.indices {
+            Some(indices) => {
+                let restart = indices.restart_value();
+                if let Indices::U16(_) = indices {
+                    // With strips the last value is reserved for restarting the strip
+                    if vertex_count > restart as usize + !strip as usize {
+                        return Err(MeshError::IndexOverflow { vertex_count });
+                    }
+                }
+                for (position, index) in indices.iter().enumerate() {
+                    if strip && index == restart {
+                        continue;
+                    }
+                    if index as usize >= vertex_count {
+                        return Err(MeshError::IndexOutOfRange {
+                            position,
+                            index,
+                            vertex_count,
+                        });
+                    }
+                }
+                indices.len()
+            }
+            None => vertex_count,
+        };
+
+        let valid_count = match self.primitive_topology {
+            wgpu::PrimitiveTopology::TriangleList => element_count % 3 == 0,
+            wgpu::PrimitiveTopology::LineList => element_count % 2 == 0,
+            _ => true,
+        };
+        if !valid_count {
+            return Err(MeshError::InvalidElementCount {
+                topology: self.primitive_topology,
+                count: element_count,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn attribute_error(
+    location: u32,
+    values: Option<&VertexAttributeValues>,
+    expected: wgpu::VertexFormat,
+) -> MeshError {
+    match values {
+        Some(values) => MeshError::InvalidAttributeFormat {
+            location,
+            expected,
+            found: values.into(),
+        },
+        None => MeshError::MissingAttribute { location },
+    }
+}
@@ -0,0 +1,130 @@
+use super::indices::Indices;
+use super::mesh::Mesh;
+use super::validation::MeshError;
+
+use std::collections::HashMap;
+
+impl Mesh {
+    /// Merge vertices whose attributes all match, allowing float components to differ by up
+    /// to `tolerance`.
+    ///
+    /// Candidates are found with a spatial hash on the positions, so this stays fast on large
+    /// meshes. Every merged vertex keeps the values of the first vertex of its group. The
+    /// result is always indexed, with [`Indices::U16`] or [`Indices::U32`] picked to fit the
+    /// new vertex count.
+    pub fn weld_vertices(&mut self, tolerance: f32) -> Result<(), MeshError> {
+        let positions = self.positions()?;
+        let tolerance = tolerance.max(0.0);
+
+        let cell_of = |position: [f32; 3]| {
+            if tolerance > 0.0 {
+                position.map(|x| (x / tolerance).floor() as i64)
+            } else {
+                // Adding zero turns -0.0 into 0.0 so both land in the same cell
+                position.map(|x| (x + 0.0).to_bits() as i64)
+            }
+        };
+        let reach = if tolerance > 0.0 { 1 } else { 0 };
+
+        let mut locations: Vec<_> = self.attributes.keys().copied().collect();
+        locations.sort_unstable();
+        let attributes: Vec<_> = locations.iter().map(|l| &self.attributes[l]).collect();
+
+        let mut cells: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+        let mut unique = Vec::new();
+        let mut remap = Vec::with_capacity(positions.len());
+        for (vertex, &position) in positions.iter().enumerate() {
+            let cell = cell_of(position);
+            let mut found = None;
+            'search: for x in -reach..=reach {
+                for y in -reach..=reach {
+                    for z in -reach..=reach {
+                        let neighbour = [cell[0] + x, cell[1] + y, cell[2] + z];
+                        for &candidate in cells.get(&neighbour).into_iter().flatten() {
+                            if attributes.iter().all(|values| {
+                                values.vertices_close(unique[candidate] as usize, vertex, tolerance)
+                            }) {
+                                found = Some(candidate);
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+
+            let id = found.unwrap_or_else(|| {
+                unique.push(vertex as u32);
+                cells.entry(cell).or_default().push(unique.len() - 1);
+                unique.len() - 1
+            });
+            remap.push(id as u32);
+        }
+
+        self.compact_vertices(&unique, &remap);
+        Ok(())
+    }
+
+    /// Index a non-indexed mesh, sharing every vertex that is repeated exactly.
+    ///
+    /// [`Indices::U16`] or [`Indices::U32`] is picked to fit the new vertex count. Meshes that
+    /// already have indices are left unchanged.
+    pub fn generate_indices(&mut self) {
+        if self.indices.is_some() {
+            return;
+        }
+
+        let mut locations: Vec<_> = self.attributes.keys().copied().collect();
+        locations.sort_unstable();
+        let attributes: Vec<_> = locations
+            .iter()
+            .map(|l| {
+                let values = &self.attributes[l];
+                (values.as_bytes(), values.item_size() as usize)
+            })
+            .collect();
+
+        let mut ids: HashMap<Vec<u8>, u32> = HashMap::new();
+        let mut unique = Vec::new();
+        let remap: Vec<u32> = (0..self.count_vertices())
+            .map(|vertex| {
+                let key: Vec<u8> = attributes
+                    .iter()
+                    .flat_map(|(bytes, size)| &bytes[vertex * size..(vertex + 1) * size])
+                    .copied()
+                    .collect();
+                *ids.entry(key).or_insert_with(|| {
+                    unique.push(vertex as u32);
+                    unique.len() as u32 - 1
+                })
+            })
+            .collect();
+
+        self.compact_vertices(&unique, &remap);
+    }
+
+    /// Keep only the `unique` vertices and point the indices at them through `remap`
+    fn compact_vertices(&mut self, unique: &[u32], remap: &[u32]) {
+        let indices: Vec<u32> = match &self.indices {
+            Some(indices) => {
+                let restart = indices.restart_value();
+                let strip = self.primitive_topology.is_strip();
+                indices
+                    .iter()
+                    .map(|index| {
+                        if strip && index == restart {
+                            u32::MAX
+                        } else {
+                            remap[index as usize]
+                        }
+                    })
+                    .collect()
+            }
+            None => remap.to_vec(),
+        };
+
+        for values in self.attributes.values_mut() {
+            *values = values.gather(unique);
+        }
+        self.indices = Some(Indices::fit(indices, unique.len()));
+    }
+}
@@ -0,0 +1,68 @@
+use super::mesh::{position_groups, Mesh};
+use super::validation::MeshError;
+
+use amel_gpu::prelude::*;
+use amel_math::prelude::*;
+use std::collections::HashMap;
+
+impl Mesh {
+    /// Get a `LineList` mesh with every unique edge of this triangle mesh.
+    ///
+    /// Edges are deduplicated by the positions of their ends, so vertices split at UV seams or
+    /// hard edges don't produce overlapping lines. The vertex data is shared with this mesh.
+    pub fn to_wireframe(&self) -> Result<Mesh, MeshError> {
+        self.edges(None)
+    }
+
+    /// Get a `LineList` mesh with the feature edges of this triangle mesh.
+    ///
+    /// An edge is a feature when the faces on either side of it meet at more than
+    /// `crease_angle` radians, or when it doesn't have exactly two faces, like borders.
+    pub fn to_feature_edges(&self, crease_angle: f32) -> Result<Mesh, MeshError> {
+        self.edges(Some(crease_angle))
+    }
+
+    fn edges(&self, crease_angle: Option<f32>) -> Result<Mesh, MeshError> {
+        let triangles = self.triangles()?;
+        let positions = self.positions()?;
+
+        let (groups, _) = position_groups(positions);
+
+        // Every unique edge in the order it was found, with the faces around it
+        let mut edge_ids = HashMap::new();
+        let mut edges: Vec<([u32; 2], Vec<usize>)> = Vec::new();
+        for (face, triangle) in triangles.iter().enumerate() {
+            for corner in 0..3 {
+                let (a, b) = (triangle[corner], triangle[(corner + 1) % 3]);
+                let (ga, gb) = (groups[a as usize], groups[b as usize]);
+                if ga == gb {
+                    continue;
+                }
+                let key = (ga.min(gb), ga.max(gb));
+                let id = *edge_ids.entry(key).or_insert_with(|| {
+                    edges.push(([a, b], Vec::new()));
+                    edges.len() - 1
+                });
+                edges[id].1.push(face);
+            }
+        }
+
+        let face_normal = |face: usize| {
+            let [a, b, c] = triangles[face].map(|i| Vec3::from(positions[i as usize]));
+            (b - a).cross(c - a).normalize_or_zero()
+        };
+        let indices: Vec<u32> = edges
+            .into_iter()
+            .filter(|(_, faces)| match (crease_angle, faces.as_slice()) {
+                (None, _) => true,
+                (Some(crease_angle), &[a, b]) => {
+                    face_normal(a).dot(face_normal(b)) < crease_angle.cos()
+                }
+                (Some(_), _) => true,
+            })
+            .flat_map(|(edge, _)| edge)
+            .collect();
+
+        Ok(self.with_topology(wgpu::PrimitiveTopology::LineList, indices))
+    }
+}
@@ -1,4 +1,5 @@
 use super::render_resources::*;
+use super::renderer::PipelineCache;
 use amel_gpu::prelude::*;
 use amel_math::prelude::*;
 use amel_mesh::prelude::*;
@@ -44,7 +45,8 @@ pub struct RenderContext<'a> {
     device: &'a wgpu::Device,
     queue: &'a wgpu::Queue,
 
-    pipeline: &'a wgpu::RenderPipeline,
+    pipelines: &'a mut PipelineCache,
+    current_pipeline: Option<MeshPrimitiveKey>,
     render_encoder: &'a mut wgpu::RenderPass<'a>,
     state: State,
     uniform_buffer: DynamicUniformBuffer,
@@ -54,7 +56,7 @@ impl<'a> RenderContext<'a> {
     pub fn new(
         device: &'a wgpu::Device,
         queue: &'a wgpu::Queue,
-        pipeline: &'a wgpu::RenderPipeline,
+        pipelines: &'a mut PipelineCache,
         render_encoder: &'a mut wgpu::RenderPass<'a>,
     ) -> Self {
         let uniform_buffer = DynamicUniformBuffer::new::<Uniforms>(
@@ -67,7 +69,8 @@ impl<'a> RenderContext<'a> {
         RenderContext {
             device,
             queue,
-            pipeline,
+            pipelines,
+            current_pipeline: None,
             render_encoder,
             state: State::default(),
             uniform_buffer,
@@ -118,6 +121,13 @@ impl<'a> RenderContext<'a> {
     }
 
     pub fn draw_mesh(&mut self, mesh: &GpuMesh) -> &mut Self {
+        let key = mesh.primitive_key();
+        let pipeline = self.pipelines.get(self.device, key);
+        if self.current_pipeline != Some(key) {
+            self.render_encoder.set_pipeline(pipeline);
+            self.current_pipeline = Some(key);
+        }
+
         let uniform_data = Uniforms::new(
             &self.state.color,
             self.state.matrix_stack.get(),
@@ -128,7 +138,7 @@ impl<'a> RenderContext<'a> {
 
         let bind_group = BindGroupBuilder::new()
             .add_entry(self.uniform_buffer.binding(0))
-            .build(self.device, &self.pipeline.get_bind_group_layout(0));
+            .build(self.device, &pipeline.get_bind_group_layout(0));
 
         self.render_encoder.set_bind_group(0, &bind_group, &[]);
         self.render_encoder.draw_mesh(mesh);
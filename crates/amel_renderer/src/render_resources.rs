@@ -1,7 +1,6 @@
 use amel_gpu::prelude::*;
 use amel_math::prelude::*;
 use amel_mesh::prelude::*;
-use bytemuck::{Pod, Zeroable};
 use std::sync::Arc;
 
 use once_cell::sync::OnceCell;
@@ -20,10 +19,9 @@ pub struct RenderResource {
 
     // cuboid: Arc<GpuMesh>,
     // wire_cuboid: Arc<GpuMesh>,
-
-    // cylinder: Arc<GpuMesh>,
-    // cone: Arc<GpuMesh>,
-    // torus: Arc<GpuMesh>,
+    cylinder: Arc<GpuMesh>,
+    cone: Arc<GpuMesh>,
+    torus: Arc<GpuMesh>,
 }
 
 impl RenderResource {
@@ -81,6 +79,20 @@ impl RenderResource {
         //         .to_gpu(device),
         // );
 
+        let cylinder = Arc::new(
+            Cylinder::new(1.0, 1.0)
+                .to_mesh_builder()
+                .build()
+                .to_gpu(device),
+        );
+        let cone = Arc::new(Cone::new(1.0, 1.0).to_mesh_builder().build().to_gpu(device));
+        let torus = Arc::new(
+            Torus::new(0.5, 1.0)
+                .to_mesh_builder()
+                .build()
+                .to_gpu(device),
+        );
+
         Self {
             // uniform_buffer,
             circle,
@@ -91,6 +103,9 @@ impl RenderResource {
             // wire_sphere,
             // cuboid,
             // wire_cuboid,
+            cylinder,
+            cone,
+            torus,
         }
     }
 
@@ -113,6 +128,18 @@ impl RenderResource {
     pub fn wire_rectangle(&self) -> Arc<GpuMesh> {
         self.wire_rectangle.clone()
     }
+
+    pub fn cylinder(&self) -> Arc<GpuMesh> {
+        self.cylinder.clone()
+    }
+
+    pub fn cone(&self) -> Arc<GpuMesh> {
+        self.cone.clone()
+    }
+
+    pub fn torus(&self) -> Arc<GpuMesh> {
+        self.torus.clone()
+    }
 }
 
 pub static RENDER_RESOURCES: OnceCell<Mutex<RenderResource>> = OnceCell::new();
@@ -1,13 +1,37 @@
 use super::render_context::RenderContext;
 use amel_gpu::prelude::*;
+use amel_mesh::prelude::*;
+use std::collections::HashMap;
+
+type PipelineBuilder = Box<dyn Fn(&wgpu::Device, MeshPrimitiveKey) -> wgpu::RenderPipeline>;
+
+/// The render pipelines of a [`Renderer`], one for each [`MeshPrimitiveKey`] drawn so far
+pub struct PipelineCache {
+    pipelines: HashMap<MeshPrimitiveKey, wgpu::RenderPipeline>,
+    build: PipelineBuilder,
+}
+
+impl PipelineCache {
+    /// Get the pipeline for `key`, building it the first time it's needed
+    pub fn get(&mut self, device: &wgpu::Device, key: MeshPrimitiveKey) -> &wgpu::RenderPipeline {
+        self.pipelines
+            .entry(key)
+            .or_insert_with(|| (self.build)(device, key))
+    }
+}
 
 pub struct Renderer {
-    pipeline: wgpu::RenderPipeline,
+    pipelines: PipelineCache,
 }
 
 impl Renderer {
-    pub fn new<'a, T: PipelineTrait<'a>>(
-        device: &'a wgpu::Device,
+    /// Create a renderer whose pipelines for `primitive_topology` are built right away.
+    ///
+    /// Meshes of any other topology can still be drawn, their pipeline is built and cached the
+    /// first time one of them is. Strip topologies need a pipeline for each strip index format,
+    /// so indexed strips with primitive restarts draw correctly next to non-indexed ones.
+    pub fn new<T: for<'a> PipelineTrait<'a> + 'static>(
+        device: &wgpu::Device,
         color_formats: Vec<Option<wgpu::TextureFormat>>,
         depth_format: Option<wgpu::TextureFormat>,
         blend_state: wgpu::BlendState,
@@ -31,15 +55,24 @@ impl Renderer {
                 .build()
         });
 
-        let pipeline = T::build(
-            device,
-            color_target_states,
-            depth_stencil_state,
-            primitive_topology,
-            sample_count,
-        );
+        let mut pipelines = PipelineCache {
+            pipelines: HashMap::new(),
+            build: Box::new(move |device, key| {
+                T::build(
+                    device,
+                    color_target_states.clone(),
+                    depth_stencil_state.clone(),
+                    key.topology,
+                    key.strip_index_format,
+                    sample_count,
+                )
+            }),
+        };
+        for key in MeshPrimitiveKey::all(primitive_topology) {
+            pipelines.get(device, key);
+        }
 
-        Renderer { pipeline }
+        Renderer { pipelines }
     }
 
     pub fn draw<F>(
@@ -89,9 +122,7 @@ impl Renderer {
                 occlusion_query_set: None,
             });
 
-            render_pass.set_pipeline(&self.pipeline);
-
-            let mut context = RenderContext::new(device, queue, &self.pipeline, &mut render_pass);
+            let mut context = RenderContext::new(device, queue, &mut self.pipelines, &mut render_pass);
             f(&mut context);
         }
 